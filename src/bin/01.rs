@@ -1,25 +1,7 @@
+use advent_of_code::helpers::{parse_blocks, top_k};
+
 pub fn get_batches(input: &str) -> Vec<Vec<u32>> {
-    let (mut acc, left) = input
-        .split('\n')
-        .map(|l| match l.parse::<u32>() {
-            Ok(u) => Some(u),
-            Err(_) => None,
-        })
-        .fold(
-            (Vec::<Vec<u32>>::new(), Vec::<u32>::new()),
-            |mut acc, x| match x {
-                Some(u) => {
-                    acc.1.push(u);
-                    acc
-                }
-                None => {
-                    acc.0.push(acc.1);
-                    (acc.0, Vec::new())
-                }
-            },
-        );
-    acc.push(left);
-    acc
+    parse_blocks(input)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -29,14 +11,9 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     let batches = get_batches(input);
-    let mut batch_sums = batches
-        .iter()
-        .map(|bp| bp.iter().sum())
-        .collect::<Vec<u32>>();
-    batch_sums.sort();
-    batch_sums.reverse();
+    let batch_sums = batches.iter().map(|bp| bp.iter().sum::<u32>());
 
-    Some(batch_sums.iter().take(3).sum())
+    Some(top_k(batch_sums, 3).iter().sum())
 }
 
 fn main() {
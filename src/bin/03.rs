@@ -1,5 +1,5 @@
+use advent_of_code::helpers::CharSet;
 use itertools::Itertools;
-use std::collections::HashSet;
 
 fn get_rucksacks(input: &str) -> Vec<(&str, &str)> {
     input
@@ -9,23 +9,18 @@ fn get_rucksacks(input: &str) -> Vec<(&str, &str)> {
         .collect()
 }
 
-fn get_compartments(input: Vec<(&str, &str)>) -> Vec<(HashSet<char>, HashSet<char>)> {
+fn get_compartments(input: Vec<(&str, &str)>) -> Vec<(CharSet, CharSet)> {
     input
         .iter()
-        .map(|(left, right)| {
-            (
-                HashSet::from_iter(left.chars()),
-                HashSet::from_iter(right.chars()),
-            )
-        })
+        .map(|(left, right)| (left.chars().collect(), right.chars().collect()))
         .collect()
 }
 
-fn get_priority(c: &char) -> u32 {
+fn get_priority(c: char) -> u32 {
     if c.is_ascii_uppercase() {
-        (*c as u32 - 64) + 26
+        (c as u32 - 64) + 26
     } else {
-        *c as u32 - 96
+        c as u32 - 96
     }
 }
 
@@ -34,7 +29,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let rscores = rucksacks
         .iter()
         .map(|(left, right)| {
-            let intersection = left.intersection(right).at_most_one().unwrap().unwrap();
+            let intersection = left.intersection(right).iter().exactly_one().unwrap();
             get_priority(intersection)
         })
         .collect_vec();
@@ -45,17 +40,15 @@ pub fn part_two(input: &str) -> Option<u32> {
     let rucksacks = input.split('\n');
     let groups = &rucksacks
         .filter(|l| !l.is_empty())
-        .map(|l| HashSet::from_iter(l.chars()))
+        .map(|l| l.chars().collect::<CharSet>())
         .chunks(3);
     let mut group_results = Vec::new();
     for chunk in groups {
         let intersection = chunk
-            .reduce(|accum: HashSet<char>, item| {
-                HashSet::from_iter(accum.intersection(&item).cloned())
-            })
-            .map(|hs| *(hs.iter().next().unwrap()))
+            .reduce(|accum, item| accum.intersection(&item))
+            .and_then(|set| set.iter().next())
             .unwrap();
-        group_results.push(get_priority(&intersection));
+        group_results.push(get_priority(intersection));
     }
     Some(group_results.iter().sum())
 }
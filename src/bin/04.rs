@@ -1,49 +1,46 @@
-use parse_display::{Display, FromStr};
+use advent_of_code::helpers::{ints_n, parse_lines, Interval};
+use parse_display::Display;
+use std::str::FromStr;
 
-#[derive(Display, FromStr, PartialEq, Eq, Debug)]
-#[display("{from}-{to}")]
-pub struct CleaningRange {
-    from: u16,
-    to: u16,
-}
-
-impl CleaningRange {
-    fn subsumed_by(&self, other: &CleaningRange) -> bool {
-        self.from >= other.from && self.to <= other.to
-    }
-
-    fn overlaps_with(&self, other: &CleaningRange) -> bool {
-        (self.from <= other.to && self.from >= other.from)
-            || (self.to >= other.from && self.to < other.to)
-    }
-}
+pub type CleaningRange = Interval<u16>;
 
-#[derive(Display, FromStr, PartialEq, Eq, Debug)]
+#[derive(Display, PartialEq, Eq, Debug)]
 #[display("{one},{two}")]
 pub struct CleaningAssignment {
     one: CleaningRange,
     two: CleaningRange,
 }
 
+impl FromStr for CleaningAssignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [from_one, to_one, from_two, to_two] =
+            ints_n(s).ok_or_else(|| "expected four numbers".to_string())?;
+        if from_one > to_one || from_two > to_two {
+            return Err("a range ends before it starts".to_string());
+        }
+        Ok(CleaningAssignment {
+            one: Interval::new(from_one, to_one),
+            two: Interval::new(from_two, to_two),
+        })
+    }
+}
+
 impl CleaningAssignment {
     fn redundant(&self) -> bool {
-        self.one.subsumed_by(&self.two) || self.two.subsumed_by(&self.one)
+        self.one.contains_interval(&self.two) || self.two.contains_interval(&self.one)
     }
 
     fn has_overlap(&self) -> bool {
-        self.one.overlaps_with(&self.two) || self.two.overlaps_with(&self.one)
+        self.one.overlaps(&self.two)
     }
 }
 
 pub fn part_one(_input: &str) -> Option<u32> {
     Some(
-        _input
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| {
-                l.parse::<CleaningAssignment>()
-                    .unwrap_or_else(|_| panic!("Could not parse {}", l))
-            })
+        parse_lines::<CleaningAssignment>(_input)
+            .iter()
             .filter(|ca| ca.redundant())
             .count()
             .try_into()
@@ -53,13 +50,8 @@ pub fn part_one(_input: &str) -> Option<u32> {
 
 pub fn part_two(_input: &str) -> Option<u32> {
     Some(
-        _input
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| {
-                l.parse::<CleaningAssignment>()
-                    .unwrap_or_else(|_| panic!("Could not parse {}", l))
-            })
+        parse_lines::<CleaningAssignment>(_input)
+            .iter()
             .filter(|ca| ca.has_overlap())
             .count()
             .try_into()
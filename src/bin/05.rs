@@ -1,6 +1,8 @@
-use parse_display::{Display, FromStr};
+use advent_of_code::helpers::{blocks, ints_n, parse_columns, parse_lines};
+use parse_display::Display;
+use std::str::FromStr;
 
-#[derive(Display, Debug, FromStr, PartialEq, Eq)]
+#[derive(Display, Debug, PartialEq, Eq)]
 #[display("move {count} from {from} to {to}")]
 pub struct MoveInst {
     count: usize,
@@ -8,44 +10,24 @@ pub struct MoveInst {
     to: usize,
 }
 
-pub fn split_instructions(input: &str) -> (Vec<Vec<char>>, Vec<MoveInst>) {
-    let mut stack_lines = Vec::new();
-    let mut instructions = Vec::new();
-    let mut indexes = Vec::new();
-    let mut stacks_width = 0;
-    for l in input.lines() {
-        if !l.is_empty() {
-            if l.starts_with("move") {
-                let inst = l
-                    .parse::<MoveInst>()
-                    .unwrap_or_else(|_| panic!("Unable to parse line {}", l));
-                instructions.push(inst);
-            } else if !l.starts_with(" 1") {
-                stack_lines.push(l);
-            } else {
-                for (i, c) in l.chars().enumerate() {
-                    if !c.is_whitespace() {
-                        indexes.push(i);
-                        stacks_width = c.to_string().parse::<usize>().unwrap();
-                    }
-                }
-            }
-        }
-    }
-    let mut stacks = Vec::new();
-    for _i in 0..stacks_width {
-        stacks.push(Vec::new());
-    }
+impl FromStr for MoveInst {
+    type Err = String;
 
-    for sline in stack_lines {
-        for (i, idx) in indexes.iter().enumerate() {
-            if let Some(c) = sline.chars().nth(*idx) {
-                if !c.is_whitespace() {
-                    stacks[i].insert(0, c);
-                }
-            }
-        }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [count, from, to] = ints_n(s).ok_or_else(|| "expected three numbers".to_string())?;
+        Ok(MoveInst { count, from, to })
     }
+}
+
+pub fn split_instructions(input: &str) -> (Vec<Vec<char>>, Vec<MoveInst>) {
+    let mut sections = blocks(input);
+    let drawing = sections.next().expect("Missing stack drawing");
+    let instructions = parse_lines(sections.next().unwrap_or_default());
+
+    let stacks = parse_columns(drawing)
+        .into_iter()
+        .map(|(_, stack)| stack)
+        .collect();
 
     (stacks, instructions)
 }
@@ -99,9 +81,38 @@ mod tests {
         assert_eq!(part_one(&input), Some("CMZ".to_string()));
     }
 
+    #[test]
+    fn test_many_stacks() {
+        let input = [
+            "                                    [K]",
+            "[A] [B] [C] [D] [E] [F] [G] [H] [I] [J]",
+            " 1   2   3   4   5   6   7   8   9   10",
+            "",
+            "move 1 from 10 to 1",
+        ]
+        .join("\n");
+        assert_eq!(part_one(&input), Some("KBCDEFGHIJ".to_string()));
+    }
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(
+            "move 3 from 1 to 2".parse(),
+            Ok(MoveInst {
+                count: 3,
+                from: 1,
+                to: 2
+            })
+        );
+        assert!("move -3 from 1 to 2".parse::<MoveInst>().is_err());
+        assert!("move 99999999999999999999 from 1 to 2"
+            .parse::<MoveInst>()
+            .is_err());
+    }
+
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(part_two(&input), Some("MCD".to_string()));
     }
 }
@@ -1,30 +1,16 @@
-pub fn find_sopm(buffer_size: usize, input: &str) -> usize {
-    let mut idx: usize = 0;
-    let mut cbuf = vec!['!'; buffer_size];
-    'outer: for c in input.trim().chars() {
-        cbuf.copy_within(1..=(buffer_size - 1), 0);
-        cbuf[(buffer_size - 1)] = c;
-        idx += 1;
-        if idx >= buffer_size {
-            for i in 0..buffer_size {
-                for j in 0..buffer_size {
-                    if i != j && cbuf[i] == cbuf[j] {
-                        continue 'outer;
-                    }
-                }
-            }
-            return idx;
-        }
-    }
-    panic!("No SOP marker found")
+use advent_of_code::helpers::first_distinct_window;
+
+/// the number of characters read until the last `buffer_size` ones were all different.
+pub fn find_sopm(buffer_size: usize, input: &str) -> Option<usize> {
+    first_distinct_window(input.trim().as_bytes(), buffer_size).map(|start| start + buffer_size)
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    Some(find_sopm(4, input))
+    find_sopm(4, input)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    Some(find_sopm(14, input))
+    find_sopm(14, input)
 }
 
 fn main() {
@@ -46,6 +32,12 @@ mod tests {
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(part_two(&input), Some(19));
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(part_one("abcabcabc"), None);
+        assert_eq!(find_sopm(4, "nppdvjthqldpwncqszvftbrmjlhg"), Some(6));
     }
 }
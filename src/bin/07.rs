@@ -1,7 +1,9 @@
-use std::cell::{Ref, RefCell};
-use std::rc::Rc;
-
+use advent_of_code::helpers::combinator::{
+    alt, map, number, pair, parse_all, preceded, rest, tag, terminated, Parser, SyntaxError,
+};
+use advent_of_code::helpers::{NodeId, Tree};
 use parse_display::{Display, FromStr};
+use std::str::FromStr;
 
 #[derive(Display, FromStr, PartialEq, Eq, Debug, Hash)]
 #[display("{size} {name}")]
@@ -10,13 +12,9 @@ pub struct File {
     size: usize,
 }
 
-#[derive(Display, FromStr, Debug, Default)]
-#[display("dir {name}")]
-#[from_str(default)]
+#[derive(Debug, Default)]
 pub struct Directory {
     name: String,
-    size: usize,
-    subdirs: Vec<Rc<RefCell<Directory>>>,
     files: Vec<File>,
 }
 
@@ -27,52 +25,9 @@ impl Directory {
             ..Default::default()
         }
     }
-
-    fn calc_size(&mut self) {
-        self.size = 0;
-        for d in self.subdirs.iter() {
-            let mut d = d.borrow_mut();
-            d.calc_size();
-            self.size += d.size;
-        }
-        for f in self.files.iter() {
-            self.size += f.size;
-        }
-    }
-
-    fn filter_subdirs<F>(&self, filter_fn: &F, recurse: bool) -> Vec<Rc<RefCell<Directory>>>
-    where
-        F: Fn(Ref<Directory>) -> bool,
-    {
-        let mut matching = Vec::new();
-        for subd in self.subdirs.iter() {
-            if recurse {
-                matching.extend(subd.borrow().filter_subdirs(filter_fn, recurse));
-            }
-            if filter_fn(subd.borrow()) {
-                matching.push(Rc::clone(subd));
-            }
-        }
-        matching
-    }
-
-    fn add_subdir(&mut self, subd: Directory) {
-        self.subdirs.push(Rc::new(RefCell::new(subd)));
-    }
-
-    fn add_file(&mut self, file: File) {
-        self.files.push(file);
-    }
-
-    fn get_mut_subdir(&self, name: &str) -> Option<Rc<RefCell<Directory>>> {
-        self.subdirs
-            .iter()
-            .find(|d| d.borrow().name.eq_ignore_ascii_case(name))
-            .cloned()
-    }
 }
 
-#[derive(Display, FromStr, PartialEq, Eq, Debug)]
+#[derive(Display, PartialEq, Eq, Debug)]
 pub enum Command {
     #[display("$ cd {0}")]
     CD(String),
@@ -84,110 +39,93 @@ pub enum Command {
     DIR(String),
 }
 
-#[derive(Debug)]
-pub struct DirStack {
-    stack: Vec<Rc<RefCell<Directory>>>,
-    root: Rc<RefCell<Directory>>,
-    current: Rc<RefCell<Directory>>,
-}
-
-impl DirStack {
-    pub fn new() -> Self {
-        let root = Directory::default();
-        let cell = Rc::new(RefCell::new(root));
-        DirStack {
-            stack: vec![cell.clone()],
-            root: cell.clone(),
-            current: cell,
-        }
-    }
-
-    pub fn cd(&mut self, name: &str) {
-        if name == "/" {
-            loop {
-                if self.current.borrow().name.is_empty() {
-                    break;
-                }
-                self.stack.pop();
-                self.current = self.stack.last().unwrap().clone();
-            }
-        } else if name == ".." {
-            self.stack.pop();
-            self.current = self.stack.last().unwrap().clone();
-        } else {
-            let next = self.current.borrow().get_mut_subdir(name).unwrap();
-            self.current = Rc::clone(&next);
-            self.stack.push(next);
-        }
-    }
-
-    pub fn add_dir(&self, dir: Directory) {
-        self.current.borrow_mut().add_subdir(dir);
-    }
+impl FromStr for Command {
+    type Err = SyntaxError;
 
-    pub fn add_file(&self, file: File) {
-        self.current.borrow_mut().add_file(file)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(command(), s)
     }
 }
 
-impl Default for DirStack {
-    fn default() -> Self {
-        Self::new()
-    }
+fn command<'a>() -> impl Parser<'a, Command> {
+    let name = || map(rest(), str::to_string);
+    alt((
+        map(preceded(tag("$ cd "), name()), Command::CD),
+        map(tag("$ ls"), |_| Command::LS),
+        map(preceded(tag("dir "), name()), Command::DIR),
+        map(
+            pair(terminated(number(), tag(" ")), name()),
+            |(size, name)| Command::FILE { size, name },
+        ),
+    ))
 }
 
-pub fn parse_commands(input: &str) -> Directory {
-    let mut stack = DirStack::new();
+pub fn parse_commands(input: &str) -> Tree<Directory> {
+    let mut tree = Tree::new(Directory::default());
+    let mut current = tree.root();
     for l in input.lines() {
-        //println!("{:#?}", stack);
-        //println!("LINE :> {}", l);
-        if let Ok(cmd) = l.parse::<Command>() {
-            match cmd {
-                Command::CD(dir) => {
-                    stack.cd(&dir);
-                }
-                Command::DIR(name) => {
-                    let new_dir = Directory::from_name(&name);
-                    stack.add_dir(new_dir);
-                }
-                Command::FILE { size, name } => {
-                    let new_file = File { size, name };
-                    stack.add_file(new_file);
+        let cmd: Command = l
+            .parse()
+            .unwrap_or_else(|e| panic!("Could not parse line {}: {}", l, e));
+        match cmd {
+            Command::CD(dir) => {
+                current = match dir.as_str() {
+                    "/" => tree.root(),
+                    ".." => tree.parent(current).expect("cannot leave the root"),
+                    name => find_subdir(&tree, current, name).unwrap(),
+                };
+            }
+            Command::DIR(name) => {
+                if find_subdir(&tree, current, &name).is_none() {
+                    tree.add_child(current, Directory::from_name(&name));
                 }
-                Command::LS => {}
             }
-        } else {
-            panic!("Could not parse line {}", l);
+            Command::FILE { size, name } => {
+                tree[current].files.push(File { size, name });
+            }
+            Command::LS => {}
         }
     }
-    let mut root = stack.root.take();
-    root.calc_size();
-    root
+    tree
+}
+
+fn find_subdir(tree: &Tree<Directory>, dir: NodeId, name: &str) -> Option<NodeId> {
+    tree.child_by_key(dir, name.to_ascii_lowercase(), |d| {
+        d.name.to_ascii_lowercase()
+    })
+}
+
+/// the total size of every directory, indexed by `NodeId::index`.
+pub fn dir_sizes(tree: &Tree<Directory>) -> Vec<usize> {
+    tree.fold_all(|dir, subdir_sizes| {
+        dir.files.iter().map(|f| f.size).sum::<usize>() + subdir_sizes.iter().sum::<usize>()
+    })
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let root = parse_commands(input);
-    let sub100k: Vec<Rc<RefCell<Directory>>> = root
-        .filter_subdirs(&|d| d.size <= 100000, true)
-        .into_iter()
-        .collect();
-    //println!("Sub: {:#?}", &sub100k);
-    let subsizes = sub100k.iter().map(|d| d.borrow().size).sum();
+    let tree = parse_commands(input);
+    let sizes = dir_sizes(&tree);
+    // the root does not count as a subdirectory.
+    let subsizes = tree
+        .ids()
+        .skip(1)
+        .map(|id| sizes[id.index()])
+        .filter(|&size| size <= 100000)
+        .sum();
     Some(subsizes)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let root = parse_commands(input);
-    let du = root.size;
+    let tree = parse_commands(input);
+    let sizes = dir_sizes(&tree);
+    let du = sizes[tree.root().index()];
     let total_size = 70000000_usize;
     let available_space = total_size - du;
-    let mut delete_candidates: Vec<usize> = root
-        .filter_subdirs(&|d| available_space + d.size > 30000000, true)
-        .iter()
-        .map(|d| d.borrow().size)
-        .collect();
-    delete_candidates.sort();
-    delete_candidates.first().copied()
+    tree.ids()
+        .skip(1)
+        .map(|id| sizes[id.index()])
+        .filter(|&size| available_space + size > 30000000)
+        .min()
 }
 
 fn main() {
@@ -211,4 +149,44 @@ mod tests {
         let input = advent_of_code::read_file("examples", 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!("$ cd ..".parse(), Ok(Command::CD("..".to_string())));
+        assert_eq!(
+            "14848514 b.txt".parse(),
+            Ok(Command::FILE {
+                size: 14848514,
+                name: "b.txt".to_string()
+            })
+        );
+        let error = "12x b.txt".parse::<Command>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 3: expected \" \", found \"x b.txt\""
+        );
+        let error = "$ mv a b".parse::<Command>().unwrap_err();
+        assert_eq!(error.offset, 0);
+        assert_eq!(
+            error.expected,
+            "\"$ cd \" or \"$ ls\" or \"dir \" or number"
+        );
+    }
+
+    #[test]
+    fn test_dir_sizes() {
+        let input = advent_of_code::read_file("examples", 7);
+        let tree = parse_commands(&input);
+        let e = tree
+            .pre_order(tree.root())
+            .find(|&id| tree[id].name == "e")
+            .unwrap();
+        let path = tree
+            .path(e)
+            .iter()
+            .map(|&id| tree[id].name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(path, vec!["", "a", "e"]);
+        assert_eq!(dir_sizes(&tree)[e.index()], 584);
+    }
 }
@@ -1,5 +1,5 @@
+use advent_of_code::helpers::{parse_lines, DenseSet2D, Direction, Point2};
 use parse_display::{Display, FromStr};
-use std::collections::HashSet;
 
 #[derive(Display, FromStr, Debug)]
 #[display("{dir} {steps}")]
@@ -8,26 +8,22 @@ pub struct Movement {
     steps: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
-pub struct Position {
-    x: i32,
-    y: i32,
-}
-
-impl Position {
-    fn distance(a: &Position, b: &Position) -> (i32, i32) {
-        ((a.x - b.x).abs(), (a.y - b.y).abs())
-    }
-
-    fn not_touching(a: &Position, b: &Position) -> bool {
-        let (d_x, d_y) = Self::distance(a, b);
-        d_x > 1 || d_y > 1
+impl Movement {
+    /// the step of the head for one move.
+    fn step(&self) -> Position {
+        // `Direction` has `y` growing downwards, but in the puzzle `U` increases `y`.
+        let offset: Position = Direction::try_from(self.dir)
+            .expect("Unknown direction")
+            .offset();
+        Position::new(offset.x, -offset.y)
     }
 }
 
+pub type Position = Point2<i32>;
+
 #[derive(Debug, Default)]
 pub struct Rope {
-    seen: HashSet<Position>,
+    seen: DenseSet2D,
     positions: Vec<Position>,
 }
 
@@ -40,49 +36,32 @@ impl Rope {
     }
 
     pub fn move_one(&mut self, m: &Movement) {
+        let step = m.step();
         for _step in 0..m.steps {
             //println!("{}: {:?}", m, self.positions);
-            let mut head = self.positions.first().unwrap().clone();
-            match m.dir {
-                'U' => head.y += 1,
-                'D' => head.y -= 1,
-                'L' => head.x -= 1,
-                'R' => head.x += 1,
-                _ => (),
-            }
+            let pos = self.pull(step);
+            self.seen.insert(pos.x.into(), pos.y.into());
+        }
+    }
+
+    /// moves the head by `step`, drags the other knots after it and returns the tail.
+    fn pull(&mut self, step: Position) -> Position {
+        self.positions[0] += step;
 
-            self.positions[0] = head;
-
-            (0..self.positions.len())
-                .map(|i| (i, i + 1))
-                .for_each(|(a, b)| {
-                    let head = self.positions.get(a).unwrap();
-                    if let Some(tail) = self.positions.get(b) {
-                        let mut tail = tail.clone();
-                        if Position::not_touching(head, &tail) {
-                            let (dx, dy) = Position::distance(head, &tail);
-                            if dx >= 1 {
-                                tail.x += (head.x - tail.x).clamp(-1, 1);
-                            }
-                            if dy >= 1 {
-                                tail.y += (head.y - tail.y).clamp(-1, 1);
-                            }
-                        }
-                        self.positions[b] = tail;
-                    }
-                });
-            self.seen.insert(self.positions.last().unwrap().clone());
+        for b in 1..self.positions.len() {
+            let head = self.positions[b - 1];
+            let tail = &mut self.positions[b];
+            if head.chebyshev(tail) > 1 {
+                *tail += (head - *tail).signum();
+            }
         }
+        *self.positions.last().unwrap()
     }
 }
 
 pub fn run_input(rope_length: usize, input: &str) -> Rope {
     let mut ht = Rope::from_vec(vec![Position::default(); rope_length]);
-    for l in input.lines() {
-        if l.trim().is_empty() {
-            continue;
-        }
-        let m: Movement = l.trim().parse().unwrap();
+    for m in parse_lines::<Movement>(input) {
         ht.move_one(&m);
     }
     ht
@@ -134,7 +113,11 @@ mod tests {
     fn test_example() {
         let input = advent_of_code::read_file("examples", 9);
         let ht = run_input(2, &input);
-        let mut seen = ht.seen.into_iter().collect_vec();
+        let mut seen = ht
+            .seen
+            .iter()
+            .map(|(x, y)| Position::new(x as i32, y as i32))
+            .collect_vec();
         seen.sort();
         assert_eq!(
             seen,
@@ -173,4 +156,47 @@ mod tests {
         assert_eq!(tail.y, 2);
         assert_eq!(tail.x, 2);
     }
+
+    /// compares the std hasher with `FastSet` for the visited positions, run it with
+    /// `cargo test --release --bin 09 -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_hashers() {
+        use advent_of_code::helpers::FastSet;
+        use std::collections::{hash_map::RandomState, HashSet};
+        use std::hash::BuildHasher;
+        use std::time::{Duration, Instant};
+
+        // a long, pseudo-random walk, so the tail visits many positions.
+        let mut seed = 42_u64;
+        let input = (0..20_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let dir = ["U", "D", "L", "R"][(seed >> 33) as usize % 4];
+                format!("{} {}", dir, (seed >> 40) % 20 + 1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // runs part two, collecting the tail positions in `seen`.
+        fn time<S: BuildHasher>(input: &str, mut seen: HashSet<Position, S>) -> (usize, Duration) {
+            let start = Instant::now();
+            let mut ht = Rope::from_vec(vec![Position::default(); 10]);
+            for m in parse_lines::<Movement>(input) {
+                let step = m.step();
+                for _ in 0..m.steps {
+                    seen.insert(ht.pull(step));
+                }
+            }
+            (seen.len(), start.elapsed())
+        }
+
+        let (std_seen, std_time) = time(&input, HashSet::<Position, RandomState>::default());
+        let (fx_seen, fx_time) = time(&input, FastSet::<Position>::default());
+        assert_eq!(std_seen, fx_seen);
+        assert_eq!(Some(fx_seen), part_two(&input));
+        println!("{} positions seen", fx_seen);
+        println!("std hasher: {:.2?}", std_time);
+        println!("fx hasher:  {:.2?}", fx_time);
+    }
 }
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use advent_of_code::helpers::parse_lines;
 use parse_display::{Display, FromStr};
 
 #[allow(non_snake_case, clippy::upper_case_acronyms)]
@@ -64,17 +64,7 @@ pub enum Instruction {
 }
 
 pub fn parse_instructions(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .filter_map(|l| {
-            let l = l.trim();
-            if !l.is_empty() {
-                Some(l.parse::<Instruction>().unwrap())
-            } else {
-                None
-            }
-        })
-        .collect_vec()
+    parse_lines(input)
 }
 
 pub fn sample_cycles(input: &str, mut cycles: Vec<usize>) -> Vec<i64> {
@@ -84,7 +74,7 @@ pub fn sample_cycles(input: &str, mut cycles: Vec<usize>) -> Vec<i64> {
     let mut output = Vec::<i64>::new();
     cycles.reverse();
     let mut sample_cycle = cycles.pop().unwrap();
-    while let true = cpu.step() {
+    while cpu.step() {
         if cpu.cycle == sample_cycle - 1 {
             output.push(sample_cycle as i64 * cpu.X);
             if let Some(next_cycle) = cycles.pop() {
@@ -108,7 +98,7 @@ pub fn part_two(input: &str) -> Option<String> {
     let mut cpu = CPU::new(insts);
     let mut output = "".to_string();
     loop {
-        if cpu.cycle % 40 == 0 && cpu.cycle != 0 {
+        if cpu.cycle.is_multiple_of(40) && cpu.cycle != 0 {
             output += "\n";
         }
         let pixel_active = cpu
@@ -168,12 +158,12 @@ mod tests {
         let input = r#"noop
         addx 3
         addx -5"#;
-        let insts = parse_instructions(&input);
+        let insts = parse_instructions(input);
         let mut cpu = CPU::new(insts);
 
         let r = cpu.step();
         // End of first cycle
-        assert_eq!(r, true);
+        assert!(r);
         assert_eq!(cpu.cycle, 1);
         assert_eq!(cpu.X, 1);
         assert_eq!(cpu.IC, 1);
@@ -0,0 +1,220 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{readme, ANSI_BOLD, ANSI_RESET};
+use std::{collections::HashMap, env, fs, path::Path, process, process::Command};
+
+const README_PATH: &str = "README.md";
+const DEFAULT_ANSWERS_PATH: &str = ".aoc/answers.txt";
+
+struct Args {
+    year: Option<i16>,
+    answers: Option<String>,
+    timings: bool,
+    dry_run: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        answers: args.opt_value_from_str(["-a", "--answers"])?,
+        timings: args.contains(["-t", "--timings"]),
+        dry_run: args.contains(["-n", "--dry-run"]),
+    })
+}
+
+/// parses confirmed answers, one `<day> <part> <answer>` entry per line.
+/// `\n` in an answer stands for a line break, e.g. for a picture that can't be decoded.
+/// empty lines and lines starting with `#` are ignored.
+fn parse_answers(input: &str) -> Result<HashMap<(u8, u8), String>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map(|(i, l)| {
+            let mut parts = l.trim().splitn(3, char::is_whitespace);
+            let day = parts.next().and_then(|d| d.parse().ok());
+            let part = parts.next().and_then(|p| p.parse().ok());
+            match (day, part, parts.next()) {
+                (Some(day), Some(part), Some(answer)) => {
+                    Ok(((day, part), answer.trim().replace("\\n", "\n")))
+                }
+                _ => Err(format!(
+                    "line {}: expected `<day> <part> <answer>`, got \"{}\"",
+                    i + 1,
+                    l
+                )),
+            }
+        })
+        .collect()
+}
+
+/// returns `None` if the solution could not be run, e.g. because its input is missing.
+fn run_day(day: u8) -> Option<Vec<advent_of_code::PartResult>> {
+    let day_padded = format!("{:02}", day);
+    let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    if cfg!(not(debug_assertions)) {
+        args.push("--release");
+    }
+
+    match Command::new("cargo").args(&args).output() {
+        Ok(cmd) if cmd.status.success() => Some(advent_of_code::parse_part_results(
+            &String::from_utf8_lossy(&cmd.stdout),
+        )),
+        Ok(_) => {
+            eprintln!(
+                "Day {} did not run successfully, keeping its current progress.",
+                day_padded
+            );
+            None
+        }
+        Err(e) => {
+            eprintln!("Failed to run day {}: {}", day_padded, e);
+            None
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let readme_text = match fs::read_to_string(README_PATH) {
+        Ok(readme_text) => readme_text,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", README_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    let year = match args
+        .year
+        .or_else(|| env::var("AOC_YEAR").ok()?.parse().ok())
+        .or_else(|| readme::table_year(&readme_text))
+    {
+        Some(year) => year,
+        None => {
+            eprintln!("Could not determine the year. Pass it with `--year`, e.g. `cargo progress --year 2022`.");
+            process::exit(1);
+        }
+    };
+
+    let answers_path = args.answers.as_deref().unwrap_or(DEFAULT_ANSWERS_PATH);
+    let answers = match fs::read_to_string(answers_path) {
+        Ok(input) => match parse_answers(&input) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to parse \"{}\": {}", answers_path, e);
+                process::exit(1);
+            }
+        },
+        Err(_) if args.answers.is_none() => HashMap::new(),
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers_path, e);
+            process::exit(1);
+        }
+    };
+
+    let previous = readme::parse_table(&readme_text);
+    let days: Vec<readme::DayProgress> = (1..=25)
+        .filter(|day| Path::new(&format!("src/bin/{:02}.rs", day)).exists())
+        .map(|day| {
+            let results = match run_day(day) {
+                Some(results) => results,
+                None => {
+                    return previous
+                        .iter()
+                        .find(|p| p.day == day)
+                        .cloned()
+                        .unwrap_or_else(|| readme::DayProgress {
+                            day,
+                            ..Default::default()
+                        })
+                }
+            };
+            let mut progress = readme::DayProgress {
+                day,
+                ..Default::default()
+            };
+            for result in results {
+                let index = match result.part {
+                    1 | 2 => usize::from(result.part - 1),
+                    _ => continue,
+                };
+                // a confirmed answer must match, otherwise any answer counts.
+                progress.stars[index] = match (&result.answer, answers.get(&(day, result.part))) {
+                    (Some(answer), Some(confirmed)) if answer != confirmed => {
+                        eprintln!(
+                            "Day {:02} part {}: answer \"{}\" does not match confirmed answer \"{}\".",
+                            day, result.part, answer, confirmed
+                        );
+                        false
+                    }
+                    (answer, _) => answer.is_some(),
+                };
+                progress.timings[index] = result.elapsed;
+            }
+            println!(
+                "{}Day {:02}{} {}",
+                ANSI_BOLD,
+                day,
+                ANSI_RESET,
+                "⭐".repeat(progress.stars.iter().filter(|s| **s).count())
+            );
+            progress
+        })
+        .collect();
+
+    let table = readme::render_table(year, &days, args.timings);
+    let updated = match readme::replace_table_section(&readme_text, &table) {
+        Some(updated) => updated,
+        None => {
+            eprintln!(
+                "Could not find the results table in \"{}\". Add two `{}` lines where it should go.",
+                README_PATH,
+                readme::TABLE_MARKER
+            );
+            process::exit(1);
+        }
+    };
+
+    if args.dry_run {
+        println!("---");
+        print!("{}", table);
+        return;
+    }
+
+    if let Err(e) = fs::write(README_PATH, updated) {
+        eprintln!("Failed to write \"{}\": {}", README_PATH, e);
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Updated the results table in \"{}\".", README_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers(
+            "# day part answer\n1 1 24000\n\n10 2 EHZFZHCZ\n5 1 two words\n6 1 #.\\n.#\n",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 4);
+        assert_eq!(answers[&(1, 1)], "24000");
+        assert_eq!(answers[&(10, 2)], "EHZFZHCZ");
+        assert_eq!(answers[&(5, 1)], "two words");
+        assert_eq!(answers[&(6, 1)], "#.\n.#");
+        assert!(parse_answers("1 24000").is_err());
+    }
+}
@@ -2,108 +2,423 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::readme;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    env::{self, temp_dir},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{self, Command},
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(_input: &str) -> Option<u32> {
-    None
+const TEMPLATE_DIR: &str = ".aoc/templates";
+const TEMPLATE_EXTENSION: &str = ".rs.tmpl";
+const DEFAULT_TEMPLATE: &str = "default";
+const PUZZLE_DIR: &str = "src/puzzles";
+const README_PATH: &str = "README.md";
+
+/// a built-in copy of `.aoc/templates/default.rs.tmpl`, used when that file does not exist.
+const MODULE_TEMPLATE: &str = include_str!("../../.aoc/templates/default.rs.tmpl");
+
+struct Args {
+    day: u8,
+    year: Option<i16>,
+    template: Option<String>,
+    answer_type: Option<String>,
+    title: Option<String>,
+    force: bool,
+    dry_run: bool,
+    offline: bool,
 }
 
-pub fn part_two(_input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        template: args.opt_value_from_str(["-t", "--template"])?,
+        answer_type: args.opt_value_from_str("--answer-type")?,
+        title: args.opt_value_from_str("--title")?,
+        force: args.contains(["-f", "--force"]),
+        dry_run: args.contains(["-n", "--dry-run"]),
+        offline: args.contains("--offline"),
+        day: args.free_from_str()?,
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn template_path(name: &str) -> PathBuf {
+    Path::new(TEMPLATE_DIR).join(format!("{}{}", name, TEMPLATE_EXTENSION))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let file_name = entry.ok()?.file_name();
+                    let name = file_name.to_str()?.strip_suffix(TEMPLATE_EXTENSION)?;
+                    Some(name.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if !names.iter().any(|n| n == DEFAULT_TEMPLATE) {
+        names.push(DEFAULT_TEMPLATE.to_string());
+    }
+    names.sort();
+    names
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let path = template_path(name.unwrap_or(DEFAULT_TEMPLATE));
+    match (fs::read_to_string(&path), name) {
+        (Ok(template), _) => Ok(template),
+        (Err(e), _) if e.kind() != io::ErrorKind::NotFound => Err(format!(
+            "Failed to read template \"{}\": {}",
+            path.display(),
+            e
+        )),
+        (Err(_), None) | (Err(_), Some(DEFAULT_TEMPLATE)) => Ok(MODULE_TEMPLATE.to_string()),
+        (Err(_), Some(name)) => Err(format!(
+            "Unknown template \"{}\". Available templates: {}",
+            name,
+            available_templates().join(", ")
+        )),
+    }
+}
+
+/// replaces every `{{name}}` placeholder with its value from `vars`.
+/// placeholders that are unknown or have no value are reported as an error.
+fn render_template(template: &str, vars: &[(&str, Option<String>)]) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let name = after.find("}}").map(|end| &after[..end]).filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c == '_' || c.is_ascii_lowercase())
+        });
+        match name {
+            Some(name) => {
+                let value = match vars.iter().find(|(var, _)| *var == name) {
+                    Some((_, Some(value))) => value,
+                    Some((_, None)) => {
+                        return Err(format!(
+                            "Template variable `{}` is not set. Pass `--{}` to set it.",
+                            name,
+                            name.replace('_', "-")
+                        ))
+                    }
+                    None => return Err(format!("Unknown template variable `{}`.", name)),
+                };
+                output.push_str(value);
+                rest = &after[name.len() + 2..];
+            }
+            None => {
+                output.push_str("{{");
+                rest = after;
+            }
+        }
     }
+    output.push_str(rest);
+    Ok(output)
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+/// reads the title from the `--- Day <day>: <title> ---` heading of a puzzle page.
+/// works for both the html page and the markdown written by aoc-cli.
+fn parse_puzzle_title(page: &str, day: u8) -> Option<String> {
+    let heading = format!("--- Day {}: ", day);
+    let rest = &page[page.find(&heading)? + heading.len()..];
+    let title = rest[..rest.find(" ---")?].trim();
+    if title.is_empty() || title.contains('\n') {
+        return None;
     }
+    Some(
+        title
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// looks for a saved copy of the puzzle page in `src/puzzles/<day>.{md,html}`.
+fn read_local_puzzle(day_padded: &str) -> Option<String> {
+    ["md", "html"].iter().find_map(|extension| {
+        fs::read_to_string(Path::new(PUZZLE_DIR).join(format!("{}.{}", day_padded, extension))).ok()
+    })
+}
+
+/// downloads the puzzle description with aoc-cli, if it is installed.
+fn download_puzzle(day: u8, year: Option<i16>) -> Option<String> {
+    if Command::new("aoc").arg("-V").output().is_err() {
+        return None;
+    }
+
+    // aoc-cli expects these files not to be present - delete just in case.
+    let puzzle_path = temp_dir().join("aoc_puzzle_tmp");
+    let input_path = temp_dir().join("aoc_scaffold_input_tmp");
+    let cleanup = || {
+        let _ = fs::remove_file(&puzzle_path);
+        let _ = fs::remove_file(&input_path);
+    };
+    cleanup();
+
+    let mut cmd_args = vec![];
+    if let Some(year) = year {
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
+    }
+    cmd_args.append(&mut vec![
+        "--puzzle-file".into(),
+        puzzle_path.to_string_lossy().to_string(),
+        "--input-file".into(),
+        input_path.to_string_lossy().to_string(),
+        "--day".into(),
+        day.to_string(),
+        "download".into(),
+    ]);
+
+    println!("Fetching puzzle title with >aoc {}", cmd_args.join(" "));
+
+    let page = match Command::new("aoc").args(cmd_args).output() {
+        Ok(output) if output.status.success() => fs::read_to_string(&puzzle_path).ok(),
+        _ => None,
+    };
+    cleanup();
+    page
+}
+
+/// the `{{header}}` of a module: the puzzle title and url as comments, if the title is known.
+fn module_header(title: Option<&str>, url: Option<&str>) -> String {
+    match (title, url) {
+        (Some(title), Some(url)) => format!("// {}\n// {}\n\n", title, url),
+        (Some(title), None) => format!("// {}\n\n", title),
+        (None, _) => String::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    Skip,
+    Overwrite,
+    Update,
+}
+
+struct ScaffoldFile {
+    label: &'static str,
+    path: String,
+    contents: String,
+    action: Action,
+}
+
+impl ScaffoldFile {
+    /// an existing file is overwritten only if `overwrite` is set, otherwise it is skipped.
+    fn new(label: &'static str, path: String, contents: String, overwrite: bool) -> Self {
+        let action = match (Path::new(&path).exists(), overwrite) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Overwrite,
+            (true, false) => Action::Skip,
+        };
+        ScaffoldFile {
+            label,
+            path,
+            contents,
+            action,
+        }
+    }
+
+    fn describe(&self, dry_run: bool) -> String {
+        let verb = match (self.action, dry_run) {
+            (Action::Create, false) => "Created",
+            (Action::Create, true) => "Would create",
+            (Action::Overwrite, false) => "Overwrote",
+            (Action::Overwrite, true) => "Would overwrite",
+            (Action::Update, false) => "Updated",
+            (Action::Update, true) => "Would update",
+            (Action::Skip, false) => "Skipped existing",
+            (Action::Skip, true) => "Would skip existing",
+        };
+        format!("{} {} \"{}\"", verb, self.label, self.path)
+    }
+}
+
+/// a file that was touched while scaffolding, and what it looked like before.
+enum Written<'a> {
+    Created(&'a str),
+    Overwrote(&'a str, Vec<u8>),
+}
+
+fn write_file(file: &ScaffoldFile) -> Result<(), io::Error> {
+    let mut options = OpenOptions::new();
+    options.write(true);
+    match file.action {
+        Action::Create => options.create_new(true),
+        _ => options.truncate(true),
+    };
+    options
+        .open(&file.path)?
+        .write_all(file.contents.as_bytes())
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// best-effort: restore every touched file to its state before scaffolding started.
+fn rollback(written: Vec<Written>) {
+    for w in written.into_iter().rev() {
+        let _ = match w {
+            Written::Created(path) => fs::remove_file(path),
+            Written::Overwrote(path, previous) => fs::write(path, previous),
+        };
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn scaffold(files: &[ScaffoldFile]) -> Result<(), (String, io::Error)> {
+    let mut written = Vec::new();
+    for file in files.iter() {
+        let result = match file.action {
+            Action::Skip => continue,
+            Action::Create => write_file(file).map(|_| Written::Created(&file.path)),
+            Action::Overwrite | Action::Update => fs::read(&file.path)
+                .and_then(|previous| write_file(file).map(|_| previous))
+                .map(|previous| Written::Overwrote(&file.path, previous)),
+        };
+        match result {
+            Ok(w) => written.push(w),
+            Err(e) => {
+                rollback(written);
+                return Err((
+                    format!("Failed to write {} \"{}\"", file.label, file.path),
+                    e,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn refuse_existing_module(module: &ScaffoldFile) -> ! {
+    eprintln!(
+        "Module \"{}\" already exists. Re-run with `--force` to overwrite it.",
+        module.path
+    );
+    process::exit(1);
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let day_padded = format!("{:02}", args.day);
+
+    let readme_text = fs::read_to_string(README_PATH).ok();
+
+    let year = args
+        .year
+        .or_else(|| env::var("AOC_YEAR").ok()?.parse().ok())
+        .or_else(|| readme::table_year(readme_text.as_ref()?));
+    let url = year.map(|year| readme::day_url(year, args.day));
+
+    let mut files = vec![
+        ScaffoldFile::new(
+            "module file",
+            format!("src/bin/{}.rs", day_padded),
+            String::new(),
+            args.force,
+        ),
+        ScaffoldFile::new(
+            "input file",
+            format!("src/inputs/{}.txt", day_padded),
+            String::new(),
+            false,
+        ),
+        ScaffoldFile::new(
+            "example file",
+            format!("src/examples/{}.txt", day_padded),
+            String::new(),
+            false,
+        ),
+    ];
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let readme_contents = readme_text
+        .zip(year)
+        .and_then(|(readme, year)| readme::add_day_row(&readme, year, args.day));
+    if let Some(contents) = readme_contents {
+        files.push(ScaffoldFile {
+            label: "results table in",
+            path: README_PATH.to_string(),
+            contents,
+            action: Action::Update,
+        });
+    }
+
+    // an existing module means the day was scaffolded before: refuse to touch anything,
+    // and don't download the puzzle title either.
+    if !args.dry_run && files[0].action == Action::Skip {
+        refuse_existing_module(&files[0]);
+    }
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let title = args.title.or_else(|| {
+        read_local_puzzle(&day_padded)
+            .or_else(|| match args.offline || args.dry_run {
+                true => None,
+                false => download_puzzle(args.day, year),
+            })
+            .and_then(|page| parse_puzzle_title(&page, args.day))
+    });
+
+    let vars = [
+        ("day", Some(args.day.to_string())),
+        ("day_padded", Some(day_padded.clone())),
+        ("year", year.map(|y| y.to_string())),
+        (
+            "header",
+            Some(module_header(title.as_deref(), url.as_deref())),
+        ),
+        ("url", url),
+        (
+            "answer_type",
+            Some(args.answer_type.unwrap_or_else(|| "u32".to_string())),
+        ),
+        (
+            "title",
+            Some(title.unwrap_or_else(|| format!("Day {}", args.day))),
+        ),
+    ];
+
+    match load_template(args.template.as_deref())
+        .and_then(|template| render_template(&template, &vars))
+    {
+        Ok(module) => files[0].contents = module,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    if args.dry_run {
+        for file in files.iter() {
+            println!("{}", file.describe(true));
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        if files[0].action == Action::Skip {
+            refuse_existing_module(&files[0]);
         }
+        return;
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
-        }
+    if let Err((msg, e)) = scaffold(&files) {
+        eprintln!("{}: {}", msg, e);
+        eprintln!("No files were changed.");
+        process::exit(1);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
+    for file in files.iter() {
+        println!("{}", file.describe(false));
     }
 
     println!("---");
@@ -112,3 +427,76 @@ fn main() {
         &day_padded
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let vars = [
+            ("day", Some("7".to_string())),
+            ("day_padded", Some("07".to_string())),
+            ("year", None),
+        ];
+        assert_eq!(
+            render_template("read_file(\"inputs\", {{day}}); // {{day_padded}}", &vars),
+            Ok("read_file(\"inputs\", 7); // 07".to_string())
+        );
+        assert_eq!(
+            render_template("format!(\"{{}}\") {{ Vec::<{{X}}>::new() }}", &vars),
+            Ok("format!(\"{{}}\") {{ Vec::<{{X}}>::new() }}".to_string())
+        );
+        assert!(render_template("{{year}}", &vars).is_err());
+        assert!(render_template("{{title}}", &vars).is_err());
+    }
+
+    #[test]
+    fn test_default_template_renders() {
+        let vars = [
+            ("day", Some("1".to_string())),
+            ("answer_type", Some("i64".to_string())),
+            ("header", Some(module_header(Some("Trebuchet?!"), None))),
+        ];
+        let module = render_template(MODULE_TEMPLATE, &vars).unwrap();
+        assert!(module.starts_with("// Trebuchet?!\n\n#[allow(unused_imports)]"));
+        assert_eq!(module.matches("Trebuchet?!").count(), 1);
+        assert!(module.contains("pub fn part_one(_input: &str) -> Option<i64>"));
+        assert!(module.contains("advent_of_code::read_file(\"examples\", 1)"));
+    }
+
+    #[test]
+    fn test_module_header() {
+        assert_eq!(
+            module_header(
+                Some("Tuning Trouble"),
+                Some("https://adventofcode.com/2022/day/6")
+            ),
+            "// Tuning Trouble\n// https://adventofcode.com/2022/day/6\n\n"
+        );
+        assert_eq!(module_header(None, Some("https://adventofcode.com")), "");
+    }
+
+    #[test]
+    fn test_parse_puzzle_title() {
+        assert_eq!(
+            parse_puzzle_title(
+                "<article class=\"day-desc\"><h2>--- Day 5: Supply Stacks ---</h2><p>",
+                5
+            ),
+            Some("Supply Stacks".to_string())
+        );
+        assert_eq!(
+            parse_puzzle_title("\\--- Day 10: Cathode-Ray Tube ---\n----------\n", 10),
+            Some("Cathode-Ray Tube".to_string())
+        );
+        assert_eq!(
+            parse_puzzle_title("<h2>--- Day 7: No Space Left On Device ---</h2>", 1),
+            None
+        );
+        assert_eq!(
+            parse_puzzle_title("<h2>--- Day 2: Rock Paper &amp; Scissors ---</h2>", 2),
+            Some("Rock Paper & Scissors".to_string())
+        );
+    }
+}
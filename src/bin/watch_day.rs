@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    fs,
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+struct Args {
+    day: u8,
+    interval: u64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        interval: args
+            .opt_value_from_str(["-i", "--interval"])?
+            .unwrap_or(500),
+        day: args.free_from_str()?,
+    })
+}
+
+/// modification times of the watched files; `None` for files that don't exist.
+fn snapshot(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn format_answer(answer: &Option<String>) -> &str {
+    answer.as_deref().unwrap_or("not solved")
+}
+
+/// describes how the answer of a part changed since the previous iteration.
+fn describe_change(previous: Option<&PartResult>, current: &PartResult) -> String {
+    let answer = format_answer(&current.answer);
+    match previous {
+        Some(previous) if previous.answer == current.answer => {
+            format!("Part {}: {} (unchanged)", current.part, answer)
+        }
+        Some(previous) => format!(
+            "Part {}: {} -> {}",
+            current.part,
+            format_answer(&previous.answer),
+            answer
+        ),
+        None => format!("Part {}: {}", current.part, answer),
+    }
+}
+
+/// runs the tests for a day. prints the test output if they fail.
+fn run_tests(day_padded: &str) -> bool {
+    match Command::new("cargo")
+        .args(["test", "--quiet", "--bin", day_padded])
+        .output()
+    {
+        Ok(cmd) if cmd.status.success() => {
+            println!("✅ Tests passed.");
+            true
+        }
+        Ok(cmd) => {
+            print!("{}", String::from_utf8_lossy(&cmd.stdout));
+            eprint!("{}", String::from_utf8_lossy(&cmd.stderr));
+            println!("❌ Tests failed, skipping solve.");
+            false
+        }
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {}", e);
+            false
+        }
+    }
+}
+
+/// runs the solution for a day and returns the result of each part.
+fn run_solve(day_padded: &str) -> Option<Vec<PartResult>> {
+    match Command::new("cargo")
+        .args(["run", "--quiet", "--bin", day_padded])
+        .output()
+    {
+        Ok(cmd) if cmd.status.success() => Some(advent_of_code::parse_part_results(
+            &String::from_utf8_lossy(&cmd.stdout),
+        )),
+        Ok(cmd) => {
+            eprint!("{}", String::from_utf8_lossy(&cmd.stderr));
+            println!("❌ Solution failed.");
+            None
+        }
+        Err(e) => {
+            eprintln!("Failed to run cargo run: {}", e);
+            None
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+    let paths = [
+        format!("src/bin/{}.rs", day_padded),
+        format!("src/examples/{}.txt", day_padded),
+        format!("src/inputs/{}.txt", day_padded),
+    ];
+
+    if fs::metadata(&paths[0]).is_err() {
+        eprintln!(
+            "Module \"{}\" does not exist. Create it with `cargo scaffold {}`.",
+            paths[0], args.day
+        );
+        process::exit(1);
+    }
+
+    println!(
+        "👀 Watching {}. Press Ctrl-C to stop.",
+        paths
+            .iter()
+            .map(|p| format!("\"{}\"", p))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut last_snapshot = None;
+    let mut last_results: Vec<PartResult> = Vec::new();
+
+    loop {
+        let current = snapshot(&paths);
+        if last_snapshot.as_ref() != Some(&current) {
+            last_snapshot = Some(current);
+
+            println!("----------");
+            println!("{}| Day {} |{}", ANSI_BOLD, day_padded, ANSI_RESET);
+            println!("----------");
+
+            if run_tests(&day_padded) {
+                if let Some(results) = run_solve(&day_padded) {
+                    for result in results.iter() {
+                        let previous = last_results.iter().find(|r| r.part == result.part);
+                        let elapsed = result
+                            .elapsed
+                            .map(|ms| format!(" {}({:.2}ms){}", ANSI_ITALIC, ms, ANSI_RESET))
+                            .unwrap_or_default();
+                        println!("{}{}", describe_change(previous, result), elapsed);
+                    }
+                    last_results = results;
+                }
+            }
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(|a| a.to_string()),
+            elapsed: None,
+        }
+    }
+
+    #[test]
+    fn test_describe_change() {
+        assert_eq!(
+            describe_change(None, &result(1, Some("24000"))),
+            "Part 1: 24000"
+        );
+        assert_eq!(
+            describe_change(Some(&result(1, Some("24000"))), &result(1, Some("24000"))),
+            "Part 1: 24000 (unchanged)"
+        );
+        assert_eq!(
+            describe_change(Some(&result(2, None)), &result(2, Some("45000"))),
+            "Part 2: not solved -> 45000"
+        );
+    }
+}
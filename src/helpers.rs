@@ -2,3 +2,42 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod charset;
+pub mod combinator;
+pub mod cycle;
+pub mod dense_set;
+pub mod direction;
+pub mod grid;
+pub mod hash;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod select;
+pub mod tree;
+pub mod window;
+
+pub use charset::CharSet;
+pub use cycle::{extrapolate, find_cycle, find_cycle_by_key, Cycle};
+pub use dense_set::DenseSet2D;
+pub use direction::{Dir8, Direction};
+pub use grid::Grid;
+pub use hash::{FastMap, FastSet, FxBuildHasher};
+pub use interval::{Interval, IntervalSet};
+pub use math::{
+    crt, extended_gcd, gcd, gcd_all, isqrt, lcm, lcm_all, mod_inverse, mod_pow, mul_mod, Int,
+    SignedInt,
+};
+pub use memo::Memo;
+pub use parse::{
+    blocks, ints, ints_n, parse_blocks, parse_columns, parse_lines, signed_ints, try_parse_blocks,
+    try_parse_lines, unsigned_ints, ParseError,
+};
+pub use point::{Point2, Point3};
+pub use search::{astar, bfs, dijkstra, grid_moves4, grid_weighted4};
+pub use select::{bottom_k, bottom_k_by_key, top_k, top_k_by_key};
+pub use tree::{NodeId, Tree};
+pub use window::{first_distinct_window, windows_with_state};
@@ -0,0 +1,170 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr};
+
+/// a set of ASCII characters stored as a bitmask, one bit per character code.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct CharSet(u128);
+
+impl CharSet {
+    pub const fn new() -> Self {
+        CharSet(0)
+    }
+
+    fn bit(c: char) -> u128 {
+        assert!(
+            c.is_ascii(),
+            "CharSet only holds ASCII characters, got {:?}",
+            c
+        );
+        1 << c as u32
+    }
+
+    /// returns whether `c` was newly inserted. panics if `c` is not ASCII.
+    pub fn insert(&mut self, c: char) -> bool {
+        let inserted = !self.contains(c);
+        self.0 |= Self::bit(c);
+        inserted
+    }
+
+    /// returns whether `c` was in the set.
+    pub fn remove(&mut self, c: char) -> bool {
+        let removed = self.contains(c);
+        if removed {
+            self.0 &= !Self::bit(c);
+        }
+        removed
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        c.is_ascii() && self.0 & Self::bit(c) != 0
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        CharSet(self.0 & other.0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        CharSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// the characters in ascending order.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+}
+
+/// iterator returned by [`CharSet::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter(u128);
+
+impl Iterator for Iter {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.0 == 0 {
+            return None;
+        }
+        let code = self.0.trailing_zeros();
+        self.0 &= self.0 - 1; // clears the lowest bit.
+        Some(char::from(code as u8))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl IntoIterator for CharSet {
+    type Item = char;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = CharSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<char> for CharSet {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.insert(c);
+        }
+    }
+}
+
+impl BitAnd for CharSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitOr for CharSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl fmt::Debug for CharSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = CharSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert('a'));
+        assert!(!set.insert('a'));
+        assert!(set.insert('Z'));
+        assert!(set.insert('\x7f'));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains('Z') && !set.contains('z') && !set.contains('ä'));
+        assert!(set.remove('a'));
+        assert!(!set.remove('a'));
+        assert_eq!(set.iter().collect::<String>(), "Z\x7f");
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: CharSet = "vJrwpWtwJgWr".chars().collect();
+        let b: CharSet = "hcsFMMfFFhFp".chars().collect();
+        assert_eq!(a.len(), 8);
+        assert_eq!(a.intersection(&b).iter().collect::<String>(), "p");
+        assert_eq!(a & b, a.intersection(&b));
+        assert_eq!((a | b).len(), 14);
+        assert_eq!(format!("{:?}", a & b), "{'p'}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_ascii() {
+        CharSet::new().insert('ß');
+    }
+}
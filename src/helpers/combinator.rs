@@ -0,0 +1,419 @@
+/*
+ * A small parser-combinator toolkit for formats that `parse_display` can't express,
+ * e.g. alternatives with good error messages or recursive structures like nested lists.
+ * Parsers are built from functions such as `tag`, `number` and `separated_list` and run with
+ * `parse_all`, which reports where parsing failed and what was expected there.
+ */
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// the not yet parsed part of the input, with its position in the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Input { source, offset: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    /// the number of bytes parsed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn advance(self, bytes: usize) -> (&'a str, Self) {
+        let parsed = &self.rest()[..bytes];
+        let rest = Input {
+            offset: self.offset + bytes,
+            ..self
+        };
+        (parsed, rest)
+    }
+
+    fn error(&self, expected: impl Into<String>) -> SyntaxError {
+        SyntaxError {
+            offset: self.offset,
+            column: self.source[..self.offset].chars().count() + 1,
+            expected: expected.into(),
+            found: self.rest().chars().take(12).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// the byte offset into the input.
+    pub offset: usize,
+    /// 1-based, counted in characters like [`super::ParseError::column`].
+    pub column: usize,
+    pub expected: String,
+    /// the start of the input that could not be parsed, empty at the end of the input.
+    pub found: String,
+}
+
+impl SyntaxError {
+    /// keeps the error that got further, or combines the expectations if both failed at the
+    /// same position.
+    fn or(self, other: SyntaxError) -> SyntaxError {
+        match self.offset.cmp(&other.offset) {
+            std::cmp::Ordering::Less => other,
+            std::cmp::Ordering::Greater => self,
+            std::cmp::Ordering::Equal => SyntaxError {
+                expected: format!("{} or {}", self.expected, other.expected),
+                ..self
+            },
+        }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "column {}: expected {}, found ",
+            self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of input")
+        } else {
+            write!(f, "\"{}\"", self.found)
+        }
+    }
+}
+
+impl Error for SyntaxError {}
+
+/// the parsed value and the remaining input, or where and why parsing failed.
+pub type PResult<'a, T> = Result<(T, Input<'a>), SyntaxError>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T>;
+}
+
+impl<'a, T, P: Parser<'a, T> + ?Sized> Parser<'a, T> for &P {
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        (**self).parse(input)
+    }
+}
+
+/// a parser defined by a function, returned by the combinators below.
+pub struct FnParser<F>(F);
+
+impl<'a, T, F: Fn(Input<'a>) -> PResult<'a, T>> Parser<'a, T> for FnParser<F> {
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        (self.0)(input)
+    }
+}
+
+/// turns a function into a parser.
+pub fn from_fn<'a, T, F: Fn(Input<'a>) -> PResult<'a, T>>(f: F) -> FnParser<F> {
+    FnParser(f)
+}
+
+/// parses all of `text`, it is an error if something is left over.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, text: &'a str) -> Result<T, SyntaxError> {
+    let (value, rest) = parser.parse(Input::new(text))?;
+    if rest.rest().is_empty() {
+        Ok(value)
+    } else {
+        Err(rest.error("end of input"))
+    }
+}
+
+/// exactly the text `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    from_fn(move |input: Input<'a>| {
+        if input.rest().starts_with(expected) {
+            let (parsed, rest) = input.advance(expected.len());
+            Ok((parsed, rest))
+        } else {
+            Err(input.error(format!("\"{}\"", expected)))
+        }
+    })
+}
+
+/// one or more characters matching `predicate`, `what` describes them in errors.
+pub fn take_while1<'a>(
+    what: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    from_fn(move |input: Input<'a>| {
+        let len = input
+            .rest()
+            .find(|c| !predicate(c))
+            .unwrap_or(input.rest().len());
+        if len == 0 {
+            return Err(input.error(what));
+        }
+        Ok(input.advance(len))
+    })
+}
+
+/// everything up to the end of the input, possibly nothing.
+pub fn rest<'a>() -> impl Parser<'a, &'a str> {
+    from_fn(|input: Input<'a>| Ok(input.advance(input.rest().len())))
+}
+
+/// an integer with an optional `-` sign.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    from_fn(|input: Input<'a>| {
+        let text = input.rest();
+        let sign = usize::from(text.starts_with('-'));
+        let digits = text[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len() - sign);
+        if digits == 0 {
+            return Err(input.error("number"));
+        }
+        let (parsed, rest) = input.advance(sign + digits);
+        match parsed.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(input.error(format!(
+                "number that fits into {}",
+                super::parse::short_type_name::<T>()
+            ))),
+        }
+    })
+}
+
+pub fn map<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> B) -> impl Parser<'a, B> {
+    from_fn(move |input| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    })
+}
+
+/// `None` instead of an error if `parser` fails.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    from_fn(move |input| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    })
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    from_fn(move |input| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    })
+}
+
+/// `parser` after `prefix`, whose value is dropped.
+pub fn preceded<'a, A, T>(
+    prefix: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// `parser` before `suffix`, whose value is dropped.
+pub fn terminated<'a, T, B>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// `parser` between `open` and `close`, e.g. brackets.
+pub fn delimited<'a, A, T, B>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// zero or more `item`s separated by `separator`.
+pub fn separated_list<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |input| {
+        let mut items = Vec::new();
+        let Ok((first, mut rest)) = item.parse(input) else {
+            return Ok((items, input));
+        };
+        items.push(first);
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (next, after_item) = item.parse(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+        Ok((items, rest))
+    })
+}
+
+/// alternative parsers, implemented for tuples of up to six parsers.
+pub trait Alt<'a, T> {
+    fn choose(&self, input: Input<'a>) -> PResult<'a, T>;
+}
+
+macro_rules! impl_alt {
+    ($($parser:ident $index:tt),+) => {
+        impl<'a, T, $($parser: Parser<'a, T>),+> Alt<'a, T> for ($($parser,)+) {
+            fn choose(&self, input: Input<'a>) -> PResult<'a, T> {
+                let mut error: Option<SyntaxError> = None;
+                $(
+                    match self.$index.parse(input) {
+                        Ok(result) => return Ok(result),
+                        Err(e) => error = Some(match error {
+                            Some(previous) => previous.or(e),
+                            None => e,
+                        }),
+                    }
+                )+
+                Err(error.unwrap())
+            }
+        }
+    };
+}
+
+impl_alt!(A 0, B 1);
+impl_alt!(A 0, B 1, C 2);
+impl_alt!(A 0, B 1, C 2, D 3);
+impl_alt!(A 0, B 1, C 2, D 3, E 4);
+impl_alt!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// the result of the first of `alternatives` that succeeds. if all fail, the error of the one
+/// that got furthest is returned.
+pub fn alt<'a, T>(alternatives: impl Alt<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |input| alternatives.choose(input))
+}
+
+/// a parser that can refer to itself, returned by [`recursive`].
+pub struct Recursive<F>(F);
+
+impl<'a, T, F> Parser<'a, T> for Recursive<F>
+where
+    F: Fn(&dyn Parser<'a, T>, Input<'a>) -> PResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        (self.0)(self, input)
+    }
+}
+
+/// a parser for recursive formats: `f` gets the parser itself and the input, e.g.
+///
+/// ```
+/// use advent_of_code::helpers::combinator::*;
+///
+/// // the depth of nested brackets such as `[[],[[]]]`.
+/// let depth = recursive(|depth, input| {
+///     let list = separated_list(depth, tag(","));
+///     map(delimited(tag("["), list, tag("]")), |depths: Vec<usize>| {
+///         depths.into_iter().max().unwrap_or(0) + 1
+///     })
+///     .parse(input)
+/// });
+/// assert_eq!(parse_all(&depth, "[[],[[]]]"), Ok(3));
+/// ```
+pub fn recursive<'a, T, F>(f: F) -> Recursive<F>
+where
+    F: Fn(&dyn Parser<'a, T>, Input<'a>) -> PResult<'a, T>,
+{
+    Recursive(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Packet {
+        Int(u32),
+        List(Vec<Packet>),
+    }
+
+    fn packet<'a>() -> impl Parser<'a, Packet> {
+        recursive(|packet, input| {
+            alt((
+                map(number(), Packet::Int),
+                map(
+                    delimited(tag("["), separated_list(packet, tag(",")), tag("]")),
+                    Packet::List,
+                ),
+            ))
+            .parse(input)
+        })
+    }
+
+    #[test]
+    fn test_basic_parsers() {
+        assert_eq!(parse_all(tag("abc"), "abc"), Ok("abc"));
+        assert_eq!(parse_all(number::<i32>(), "-42"), Ok(-42));
+        assert_eq!(
+            parse_all(
+                pair(terminated(number::<u8>(), tag("-")), number::<u8>()),
+                "2-4"
+            ),
+            Ok((2, 4))
+        );
+        assert_eq!(
+            parse_all(separated_list(number::<u8>(), tag(", ")), "1, 2, 3"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse_all(separated_list(number::<u8>(), tag(",")), ""),
+            Ok(vec![])
+        );
+        assert_eq!(
+            parse_all(
+                pair(opt(tag("-")), take_while1("letters", char::is_alphabetic)),
+                "ab"
+            ),
+            Ok((None, "ab"))
+        );
+        assert_eq!(parse_all(preceded(tag("x="), rest()), "x=..."), Ok("..."));
+    }
+
+    #[test]
+    fn test_recursive() {
+        use Packet::*;
+        assert_eq!(
+            parse_all(packet(), "[1,[2,[]],3]"),
+            Ok(List(vec![Int(1), List(vec![Int(2), List(vec![])]), Int(3)]))
+        );
+        assert_eq!(parse_all(packet(), "7"), Ok(Int(7)));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = parse_all(packet(), "[1,[2,x]]").unwrap_err();
+        assert_eq!(error.offset, 6);
+        assert_eq!(error.expected, "number or \"[\"");
+        assert_eq!(
+            error.to_string(),
+            "column 7: expected number or \"[\", found \"x]]\""
+        );
+
+        let error = parse_all(packet(), "[1,2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 5: expected \"]\", found end of input"
+        );
+
+        let error = parse_all(number::<u8>(), "300").unwrap_err();
+        assert_eq!(error.expected, "number that fits into u8");
+
+        let error = parse_all(pair(tag("ä="), number::<u8>()), "ä=x").unwrap_err();
+        assert_eq!((error.offset, error.column), (3, 3));
+
+        let error = parse_all(tag("ab"), "abc").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 3: expected end of input, found \"c\""
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// a sequence of states that repeats from step `start` on, every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// the number of steps before the sequence enters the cycle.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// the step before `start + period` that reaches the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// the value at step `n` of something that grows by the same amount every period,
+    /// e.g. a height or a count. `value(i)` is only called for `i <= start + period`.
+    pub fn extrapolate_value(&self, n: usize, mut value: impl FnMut(usize) -> i64) -> i64 {
+        if n < self.start + self.period {
+            return value(n);
+        }
+        let periods = ((n - self.start) / self.period) as i64;
+        let growth = value(self.start + self.period) - value(self.start);
+        value(self.equivalent_step(n)) + periods * growth
+    }
+}
+
+/// finds the cycle of the states produced by repeatedly applying `step` to `initial`,
+/// with Brent's algorithm. only keeps two states in memory, but never returns if the states
+/// do not repeat.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // find the period by moving the hare ahead of the tortoise in increasing powers of two.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare one period ahead, both meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// like [`find_cycle`], but compares states by `key` and remembers every key it has seen.
+/// useful if only part of a state repeats, e.g. a position while a counter keeps growing.
+/// also returns the states of the steps before `start + period`, so the state at step `n` is
+/// `states[cycle.equivalent_step(n)]`.
+pub fn find_cycle_by_key<S, K, F, FK>(initial: S, mut step: F, mut key: FK) -> (Cycle, Vec<S>)
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    FK: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(&start) = seen.get(&key(&next)) {
+            let period = states.len() - start;
+            return (Cycle { start, period }, states);
+        }
+        seen.insert(key(&next), states.len());
+        states.push(next);
+    }
+}
+
+/// the state after `n` applications of `step` to `initial`, taking a shortcut through
+/// the cycle found by [`find_cycle`].
+pub fn extrapolate<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let cycle = find_cycle(initial.clone(), &mut step);
+    let mut state = initial;
+    for _ in 0..cycle.equivalent_step(n) {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts down from 100 to 0 in 10 steps, then cycles through 0, 3, 6, 2, 5, 1, 4.
+    fn step(x: &u32) -> u32 {
+        if *x >= 10 {
+            x - 10
+        } else {
+            (x + 3) % 7
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle {
+            start: 10,
+            period: 7,
+        };
+        assert_eq!(find_cycle(100, step), expected);
+        let (cycle, states) = find_cycle_by_key(100, step, |x| *x);
+        assert_eq!(cycle, expected);
+        assert_eq!(states.len(), 17);
+        assert_eq!(states[cycle.equivalent_step(1_000_000)], 1);
+
+        assert_eq!(
+            find_cycle(0, step),
+            Cycle {
+                start: 0,
+                period: 7
+            }
+        );
+        assert_eq!(
+            find_cycle(4, |_| 4),
+            Cycle {
+                start: 0,
+                period: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_cycle_by_key() {
+        // the position repeats, the step counter does not.
+        let (cycle, states) = find_cycle_by_key((100, 0), |&(x, n)| (step(&x), n + 1), |s| s.0);
+        assert_eq!(cycle.start, 10);
+        assert_eq!(cycle.period, 7);
+        assert_eq!(states[12], (6, 12));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let n = 1_000_000;
+        let mut state = 100;
+        for _ in 0..n {
+            state = step(&state);
+        }
+        assert_eq!(extrapolate(100, step, n), state);
+        assert_eq!(extrapolate(100, step, 3), 70);
+    }
+
+    #[test]
+    fn test_extrapolate_value() {
+        let cycle = Cycle {
+            start: 0,
+            period: 3,
+        };
+        let value = |n: usize| (2 * n + n % 3) as i64;
+        for n in [0, 2, 3, 10, 1_000_000_000_000] {
+            assert_eq!(cycle.extrapolate_value(n, value), value(n));
+        }
+        let cycle = Cycle {
+            start: 2,
+            period: 4,
+        };
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(6), 2);
+        assert_eq!(cycle.equivalent_step(13), 5);
+    }
+}
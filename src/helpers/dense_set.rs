@@ -0,0 +1,250 @@
+use std::fmt;
+
+/// a set of 2d integer points stored as one `bool` per cell of a rectangle, which grows in all
+/// directions as needed. faster than a `HashSet` for points that lie close together, e.g. the
+/// tiles visited by a walk. the rectangle always covers the bounding box of all points, so two
+/// far-apart points such as `(0, 0)` and `(100_000, 100_000)` allocate all cells in between.
+#[derive(Debug, Clone, Default)]
+pub struct DenseSet2D {
+    /// the position of the first cell of `cells`.
+    origin: (i64, i64),
+    width: usize,
+    height: usize,
+    /// one byte per cell, row by row.
+    cells: Vec<bool>,
+    len: usize,
+    /// the smallest and largest coordinates of the points in the set.
+    bounds: Option<((i64, i64), (i64, i64))>,
+}
+
+impl DenseSet2D {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        let (dx, dy) = (x - self.origin.0, y - self.origin.1);
+        (dx >= 0 && dy >= 0 && (dx as usize) < self.width && (dy as usize) < self.height)
+            .then(|| dy as usize * self.width + dx as usize)
+    }
+
+    /// reallocates the cells to include `(x, y)`, with room to grow further in that direction.
+    fn grow(&mut self, x: i64, y: i64) {
+        let (old_x, old_y) = self.origin;
+        let (old_width, old_height) = (self.width as i64, self.height as i64);
+        let (min_x, min_y, max_x, max_y) = if self.cells.is_empty() {
+            (x, y, x, y)
+        } else {
+            // doubling the size on the side that overflows keeps inserts amortized O(1).
+            let (grow_x, grow_y) = (old_width.max(8), old_height.max(8));
+            (
+                if x < old_x {
+                    x.min(old_x - grow_x)
+                } else {
+                    old_x
+                },
+                if y < old_y {
+                    y.min(old_y - grow_y)
+                } else {
+                    old_y
+                },
+                if x >= old_x + old_width {
+                    x.max(old_x + old_width - 1 + grow_x)
+                } else {
+                    old_x + old_width - 1
+                },
+                if y >= old_y + old_height {
+                    y.max(old_y + old_height - 1 + grow_y)
+                } else {
+                    old_y + old_height - 1
+                },
+            )
+        };
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut cells = vec![false; width * height];
+        for row in 0..self.height {
+            let old_start = row * self.width;
+            let start = (old_y - min_y) as usize * width + row * width + (old_x - min_x) as usize;
+            cells[start..start + self.width]
+                .copy_from_slice(&self.cells[old_start..old_start + self.width]);
+        }
+        self.origin = (min_x, min_y);
+        self.width = width;
+        self.height = height;
+        self.cells = cells;
+    }
+
+    /// returns whether the point was newly inserted.
+    pub fn insert(&mut self, x: i64, y: i64) -> bool {
+        let index = match self.index(x, y) {
+            Some(index) => index,
+            None => {
+                self.grow(x, y);
+                self.index(x, y).unwrap()
+            }
+        };
+        if self.cells[index] {
+            return false;
+        }
+        self.cells[index] = true;
+        self.len += 1;
+        self.bounds = Some(match self.bounds {
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            }
+            None => ((x, y), (x, y)),
+        });
+        true
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.index(x, y).is_some_and(|index| self.cells[index])
+    }
+
+    /// the number of points in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// the smallest and the largest `(x, y)` of the points, `None` if the set is empty.
+    pub fn bounding_box(&self) -> Option<((i64, i64), (i64, i64))> {
+        self.bounds
+    }
+
+    /// the points row by row, from the top left.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &set)| set)
+            .map(|(i, _)| {
+                (
+                    self.origin.0 + (i % self.width) as i64,
+                    self.origin.1 + (i / self.width) as i64,
+                )
+            })
+    }
+
+    /// draws the bounding box with `set` for the points in the set and `unset` for all others.
+    pub fn render(&self, set: char, unset: char) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds else {
+            return String::new();
+        };
+        (min_y..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| if self.contains(x, y) { set } else { unset })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl FromIterator<(i64, i64)> for DenseSet2D {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        let mut set = DenseSet2D::new();
+        for (x, y) in iter {
+            set.insert(x, y);
+        }
+        set
+    }
+}
+
+/// renders the set with `#` and `.`.
+impl fmt::Display for DenseSet2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render('#', '.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains() {
+        let mut set = DenseSet2D::new();
+        assert!(set.is_empty());
+        assert_eq!(set.bounding_box(), None);
+        assert!(set.insert(0, 0));
+        assert!(!set.insert(0, 0));
+        // grow in all four directions.
+        for (x, y) in [(5, 0), (-3, 2), (0, -7), (0, 9), (-100, 100)] {
+            assert!(set.insert(x, y));
+        }
+        assert_eq!(set.len(), 6);
+        for (x, y) in [(0, 0), (5, 0), (-3, 2), (0, -7), (0, 9), (-100, 100)] {
+            assert!(set.contains(x, y), "({}, {})", x, y);
+        }
+        assert!(!set.contains(1, 0) && !set.contains(1000, 1000));
+        assert_eq!(set.bounding_box(), Some(((-100, -7), (5, 100))));
+        assert_eq!(set.iter().count(), 6);
+        assert_eq!(set.iter().next(), Some((0, -7)));
+    }
+
+    #[test]
+    fn test_many_points() {
+        let points: Vec<(i64, i64)> = (-500..500).map(|i| (i * 3 % 71, -i)).collect();
+        let set: DenseSet2D = points.iter().rev().copied().collect();
+        assert_eq!(set.len(), 1000);
+        assert!(points.iter().all(|&(x, y)| set.contains(x, y)));
+    }
+
+    #[test]
+    fn test_render() {
+        let set: DenseSet2D = [(1, 1), (2, 1), (3, 2), (1, 3)].into_iter().collect();
+        assert_eq!(set.to_string(), "##.\n..#\n#..");
+        assert_eq!(set.render('█', ' '), "██ \n  █\n█  ");
+        assert_eq!(DenseSet2D::new().to_string(), "");
+    }
+
+    /// compares `DenseSet2D` with hash sets using the std hasher and `FxBuildHasher` on the
+    /// tiles of a random walk, run it with
+    /// `cargo test --release --lib dense_set -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_walk() {
+        use crate::helpers::FastSet;
+        use std::collections::HashSet;
+        use std::time::Instant;
+
+        let mut seed = 42_u64;
+        let (mut x, mut y) = (0_i64, 0_i64);
+        let walk: Vec<(i64, i64)> = (0..2_000_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                match (seed >> 33) % 4 {
+                    0 => x += 1,
+                    1 => x -= 1,
+                    2 => y += 1,
+                    _ => y -= 1,
+                }
+                (x, y)
+            })
+            .collect();
+
+        let start = Instant::now();
+        let std_len = walk.iter().copied().collect::<HashSet<_>>().len();
+        let std_time = start.elapsed();
+        let start = Instant::now();
+        let fx_len = walk.iter().copied().collect::<FastSet<_>>().len();
+        let fx_time = start.elapsed();
+        let start = Instant::now();
+        let dense_len = walk.iter().copied().collect::<DenseSet2D>().len();
+        let dense_time = start.elapsed();
+
+        assert_eq!(std_len, fx_len);
+        assert_eq!(std_len, dense_len);
+        println!("{} positions seen", dense_len);
+        println!("std hasher: {:.2?}", std_time);
+        println!("fx hasher:  {:.2?}", fx_time);
+        println!("dense set:  {:.2?}", dense_time);
+    }
+}
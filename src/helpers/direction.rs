@@ -0,0 +1,205 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::math::SignedInt;
+use super::point::Point2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse \"{}\" as a direction", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// one of the four cardinal directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// in clockwise order, starting with north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// the `(dx, dy)` step of one move in this direction.
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// the step of one move in this direction as a point.
+    pub fn offset<T: SignedInt>(&self) -> Point2<T> {
+        match self {
+            Direction::North => Point2::new(T::ZERO, -T::ONE),
+            Direction::East => Point2::new(T::ONE, T::ZERO),
+            Direction::South => Point2::new(T::ZERO, T::ONE),
+            Direction::West => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// accepts `U/D/L/R`, `^v<>` and `N/E/S/W`.
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::North),
+            'R' | '>' | 'E' => Ok(Direction::East),
+            'D' | 'v' | 'S' => Ok(Direction::South),
+            'L' | '<' | 'W' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// one of the eight cardinal and intercardinal directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// in clockwise order, starting with north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// the `(dx, dy)` step of one move in this direction.
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Dir8::North => (0, -1),
+            Dir8::NorthEast => (1, -1),
+            Dir8::East => (1, 0),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (0, 1),
+            Dir8::SouthWest => (-1, 1),
+            Dir8::West => (-1, 0),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// the step of one move in this direction as a point.
+    pub fn offset<T: SignedInt>(&self) -> Point2<T> {
+        let (dx, dy) = self.delta();
+        let unit = |d: i64| match d {
+            -1 => -T::ONE,
+            1 => T::ONE,
+            _ => T::ZERO,
+        };
+        Point2::new(unit(dx), unit(dy))
+    }
+
+    /// turns by 45 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Self {
+        Dir8::ALL[(*self as usize + 7) % 8]
+    }
+
+    /// turns by 45 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        Dir8::ALL[(*self as usize + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Dir8::ALL[(*self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(dir: Direction) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthWest.turn_right(), Dir8::North);
+        assert_eq!(Dir8::SouthEast.opposite(), Dir8::NorthWest);
+        assert_eq!(Dir8::from(Direction::South), Dir8::South);
+    }
+
+    #[test]
+    fn test_parse() {
+        for (chars, dir) in ["U^N", "R>E", "DvS", "L<W"].iter().zip(Direction::ALL) {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(dir));
+            }
+        }
+        assert_eq!("L".parse(), Ok(Direction::West));
+        assert!("LR".parse::<Direction>().is_err());
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        for dir in Direction::ALL {
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.offset::<i64>(), Point2::new(dx, dy));
+            assert_eq!(Dir8::from(dir).offset::<i64>(), Point2::new(dx, dy));
+        }
+        assert_eq!(Dir8::SouthWest.offset::<i32>(), Point2::new(-1, 1));
+    }
+}
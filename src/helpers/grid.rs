@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::Direction;
+
+const NEIGHBOURS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// a rectangular grid stored as a flat, row-major `Vec`.
+/// `x` is the column and `y` the row, `(0, 0)` is the top left cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// panics if `cells` does not fill a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fit into rows of width {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// parses one cell per character, one row per non-empty line. only the line terminators
+    /// are stripped, so leading and trailing spaces are cells as well.
+    /// returns `None` if `f` rejects a character or the lines differ in length.
+    pub fn parse_with<F>(input: &str, mut f: F) -> Option<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines().filter(|l| !l.is_empty()) {
+            let len_before = cells.len();
+            for c in line.chars() {
+                cells.push(f(c)?);
+            }
+            let line_width = cells.len() - len_before;
+            if *width.get_or_insert(line_width) != line_width {
+                return None;
+            }
+        }
+        Some(Grid::from_vec(width?, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, x: i64, y: i64) -> Option<usize> {
+        let in_bounds = x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height;
+        in_bounds.then(|| y as usize * self.width + x as usize)
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.index_of(x, y).is_some()
+    }
+
+    /// returns `None` for coordinates outside of the grid, including negative ones.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// all `(x, y)` coordinates in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// all cells with their `(x, y)` coordinates in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: i64,
+        y: i64,
+        deltas: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = ((i64, i64), &'a T)> {
+        deltas.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x + dx, y + dy);
+            self.get(nx, ny).map(|cell| ((nx, ny), cell))
+        })
+    }
+
+    /// the up to four orthogonally adjacent cells that lie within the grid.
+    pub fn neighbours4(&self, x: i64, y: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// the up to eight orthogonally and diagonally adjacent cells that lie within the grid.
+    pub fn neighbours8(&self, x: i64, y: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    /// the cells seen when walking from `(x, y)` in `direction` until the edge of the grid.
+    /// the starting cell is not included.
+    pub fn ray(&self, x: i64, y: i64, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            x,
+            y,
+            delta: direction.delta(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::from_vec(width, vec![fill; width * height])
+    }
+
+    /// mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::from_vec(self.height, cells)
+    }
+
+    /// rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Grid::from_vec(self.height, cells)
+    }
+
+    /// rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::from_vec(self.height, cells)
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Option<Self> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl Grid<u32> {
+    /// parses a grid of decimal digits, e.g. tree heights.
+    pub fn parse_digits(input: &str) -> Option<Self> {
+        Grid::parse_with(input, |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "x {} is out of bounds", x);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "x {} is out of bounds", x);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// iterator returned by [`Grid::ray`].
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    x: i64,
+    y: i64,
+    delta: (i64, i64),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.x += self.delta.0;
+        self.y += self.delta.1;
+        self.grid.get(self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u32> {
+        Grid::parse_digits("123\n456\n").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(Grid::parse_digits("12\n3\n"), None);
+        assert_eq!(Grid::parse_digits("1a\n"), None);
+        assert_eq!(Grid::parse_digits("12\r\n34\r\n").unwrap().width(), 2);
+        let grid = Grid::parse_chars("a  \nbcd").unwrap();
+        assert_eq!((grid.width(), grid[(2, 0)]), (3, ' '));
+        assert_eq!(
+            Grid::parse_with("#.\n.#", |c| Some(c == '#'))
+                .unwrap()
+                .to_string(),
+            "truefalse\nfalsetrue"
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = example();
+        assert_eq!(grid.get(1, 0), Some(&2));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        *grid.get_mut(0, 1).unwrap() = 9;
+        assert_eq!(grid.to_string(), "123\n956");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let n4: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(n4, vec![((1, 0), &2), ((0, 1), &4)]);
+        let n8: Vec<u32> = grid.neighbours8(1, 0).map(|(_, c)| *c).collect();
+        assert_eq!(n8, vec![3, 6, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse_digits("30373\n25512\n65332\n33549\n35390").unwrap();
+        let north: Vec<u32> = grid.ray(2, 3, Direction::North).copied().collect();
+        assert_eq!(north, vec![3, 5, 3]);
+        let east: Vec<u32> = grid.ray(2, 3, Direction::East).copied().collect();
+        assert_eq!(east, vec![4, 9]);
+        assert_eq!(grid.ray(0, 0, Direction::West).next(), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * A fast, non-cryptographic hasher in the style of `FxHash` from the Rust compiler.
+ * It is much faster than the default SipHash for small keys such as integers and points,
+ * but offers no protection against inputs crafted to collide, which puzzles don't have.
+ */
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_to_hash(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut rest = chunks.remainder();
+        if rest.len() >= 4 {
+            self.add_to_hash(u32::from_le_bytes(rest[..4].try_into().unwrap()) as u64);
+            rest = &rest[4..];
+        }
+        for &b in rest {
+            self.add_to_hash(b as u64);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// a `HashMap` using [`FxHasher`], create one with `FastMap::default()`.
+pub type FastMap<K, V> = HashMap<K, V, FxBuildHasher>;
+
+/// a `HashSet` using [`FxHasher`], create one with `FastSet::default()`.
+pub type FastSet<T> = HashSet<T, FxBuildHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        FxBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_hasher() {
+        assert_eq!(hash(42_u32), hash(42_u32));
+        assert_ne!(hash(42_u32), hash(43_u32));
+        assert_ne!(hash((1, 2)), hash((2, 1)));
+        assert_ne!(hash("abcdefghijk"), hash("abcdefghijl"));
+        assert_ne!(hash([1_u8, 2, 3]), hash([1_u8, 2, 4]));
+    }
+
+    #[test]
+    fn test_collections() {
+        let mut map = FastMap::default();
+        map.insert("a", 1);
+        *map.entry("a").or_insert(0) += 1;
+        assert_eq!(map["a"], 2);
+        let set: FastSet<(i32, i32)> = (0..100).map(|i| (i, -i)).collect();
+        assert_eq!(set.len(), 100);
+        assert!(set.contains(&(7, -7)));
+    }
+}
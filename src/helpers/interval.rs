@@ -0,0 +1,329 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::math::Int;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError(String);
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse \"{}\" as an interval", self.0)
+    }
+}
+
+impl Error for ParseIntervalError {}
+
+/// the integers from `start` to `end`, both inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Int> Interval<T> {
+    /// panics if `start > end`.
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval must not end before it starts");
+        Interval { start, end }
+    }
+
+    /// the number of integers in the interval. panics if it does not fit into `T`, e.g. for
+    /// `0-255` as `u8` or `-100-100` as `i8`.
+    pub fn len(&self) -> T {
+        self.end
+            .checked_sub(self.start)
+            .and_then(|len| len.checked_add(T::ONE))
+            .expect("interval length overflows")
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// whether `other` lies completely within this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// whether the intervals share at least one integer.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// whether the intervals overlap or one starts right after the other ends.
+    pub fn touches(&self, other: &Self) -> bool {
+        self.overlaps(other)
+            || (self.end < other.start && other.start - self.end == T::ONE)
+            || (other.end < self.start && self.start - other.end == T::ONE)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// the merged interval, or `None` if there would be a gap between both.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        self.touches(other).then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// the parts of this interval that are not covered by `other`, at most two.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let (before, after) = if self.overlaps(other) {
+            (
+                (self.start < other.start).then(|| Interval {
+                    start: self.start,
+                    end: other.start - T::ONE,
+                }),
+                (other.end < self.end).then(|| Interval {
+                    start: other.end + T::ONE,
+                    end: self.end,
+                }),
+            )
+        } else {
+            (Some(*self), None)
+        };
+        before.into_iter().chain(after)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// parses `start-end`, e.g. `2-4` or `-5--3`.
+impl<T: Int + FromStr> FromStr for Interval<T> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseIntervalError(s.to_string());
+        let text = s.trim();
+        // skip the first character, it may be the sign of `start`.
+        let split = text
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(err)?;
+        let start = text[..split].trim().parse().map_err(|_| err())?;
+        let end = text[split + 1..].trim().parse().map_err(|_| err())?;
+        if start > end {
+            return Err(err());
+        }
+        Ok(Interval { start, end })
+    }
+}
+
+/// a set of integers stored as sorted, disjoint intervals.
+/// overlapping and adjacent intervals are merged, so `1-2` and `3-4` are kept as `1-4`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Int> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // the intervals before `first` end too early and the ones from `last` on start too late
+        // to be merged with `interval`.
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < interval.start && !i.touches(&interval));
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end || i.touches(&interval));
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |acc, i| acc.union(i).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let rest: Vec<_> = self.intervals[first..last]
+            .iter()
+            .flat_map(|i| i.difference(&interval))
+            .collect();
+        self.intervals.splice(first..last, rest);
+    }
+
+    /// the integers of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for interval in &other.intervals {
+            set.remove(*interval);
+        }
+        set
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    /// the number of integers in the set. panics if it does not fit into `T`.
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |total, i| {
+            total
+                .checked_add(i.len())
+                .expect("interval set length overflows")
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// the disjoint intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Int> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end)
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = iv(2, 6);
+        assert_eq!(a.len(), 5);
+        assert!(a.contains(2) && a.contains(6) && !a.contains(7));
+        assert!(a.contains_interval(&iv(3, 6)));
+        assert!(!a.contains_interval(&iv(3, 7)));
+        // the boundary cases are symmetric.
+        assert!(a.overlaps(&iv(6, 8)) && iv(6, 8).overlaps(&a));
+        assert!(a.overlaps(&iv(0, 2)) && iv(0, 2).overlaps(&a));
+        assert!(!a.overlaps(&iv(7, 8)) && !iv(7, 8).overlaps(&a));
+        assert_eq!(a.intersection(&iv(4, 9)), Some(iv(4, 6)));
+        assert_eq!(a.intersection(&iv(7, 9)), None);
+        assert_eq!(a.union(&iv(7, 9)), Some(iv(2, 9)));
+        assert_eq!(iv(7, 9).union(&a), Some(iv(2, 9)));
+        assert_eq!(a.union(&iv(8, 9)), None);
+        assert_eq!(
+            a.difference(&iv(3, 4)).collect::<Vec<_>>(),
+            vec![iv(2, 2), iv(5, 6)]
+        );
+        assert_eq!(a.difference(&iv(0, 9)).count(), 0);
+        assert_eq!(a.difference(&iv(8, 9)).collect::<Vec<_>>(), vec![a]);
+    }
+
+    #[test]
+    fn test_unsigned_bounds() {
+        let a = Interval::new(0_u8, 255);
+        assert!(a.touches(&Interval::new(0, 0)));
+        assert_eq!(
+            a.difference(&Interval::new(0, 0)).next(),
+            Some(Interval::new(1, 255))
+        );
+        assert_eq!(
+            a.difference(&Interval::new(255, 255)).next(),
+            Some(Interval::new(0, 254))
+        );
+    }
+
+    #[test]
+    fn test_len_limit() {
+        assert_eq!(Interval::new(0_u8, 254).len(), 255);
+        assert_eq!(Interval::new(-128_i8, -2).len(), 127);
+        let set: IntervalSet<u8> = [Interval::new(0, 99), Interval::new(101, 255)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval length overflows")]
+    fn test_len_overflow() {
+        Interval::new(0_u8, 255).len();
+    }
+
+    #[test]
+    #[should_panic(expected = "interval length overflows")]
+    fn test_len_overflow_signed() {
+        Interval::new(-100_i8, 100).len();
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("2-4".parse(), Ok(iv(2, 4)));
+        assert_eq!("-5--3".parse(), Ok(iv(-5, -3)));
+        assert_eq!(iv(-5, -3).to_string(), "-5--3");
+        assert!("4-2".parse::<Interval<i32>>().is_err());
+        assert!("4".parse::<Interval<i32>>().is_err());
+        assert!("a-b".parse::<Interval<u8>>().is_err());
+    }
+
+    #[test]
+    fn test_set_insert() {
+        let set: IntervalSet<i32> = [iv(10, 12), iv(1, 2), iv(5, 6), iv(3, 3), iv(20, 25)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![iv(1, 3), iv(5, 6), iv(10, 12), iv(20, 25)]
+        );
+        assert_eq!(set.len(), 14);
+        assert!(set.contains(11) && !set.contains(4) && !set.contains(26));
+
+        let mut merged = set.clone();
+        merged.insert(iv(4, 19));
+        assert_eq!(merged.iter().copied().collect::<Vec<_>>(), vec![iv(1, 25)]);
+        let empty = IntervalSet::<i32>::new();
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty() && !set.is_empty());
+    }
+
+    #[test]
+    fn test_set_remove() {
+        let mut set: IntervalSet<i32> = [iv(0, 10), iv(20, 30)].into_iter().collect();
+        set.remove(iv(5, 25));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![iv(0, 4), iv(26, 30)]
+        );
+        set.remove(iv(0, 4));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![iv(26, 30)]);
+
+        let other: IntervalSet<i32> = [iv(27, 27), iv(29, 40)].into_iter().collect();
+        assert_eq!(
+            set.difference(&other).iter().copied().collect::<Vec<_>>(),
+            vec![iv(26, 26), iv(28, 28)]
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// a primitive integer type. the numeric helpers, such as intervals, path costs and point
+/// coordinates, are generic over it.
+pub trait Int:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    /// the remainder in `0..rhs.abs()`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// a signed primitive integer type.
+pub trait SignedInt: Int + Neg<Output = Self> {
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    /// panics for `Self::MIN` in debug builds.
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_int {
+    ($($t:ty),*) => {
+        $(
+            impl SignedInt for $t {
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+
+/// the greatest common divisor, never negative. `gcd(0, 0)` is 0.
+/// `None` if it overflows, which only happens for `gcd(T::MIN, 0)` and `gcd(T::MIN, T::MIN)`.
+pub fn gcd<T: SignedInt>(a: T, b: T) -> Option<T> {
+    // work with non-positive numbers: unlike `abs`, negating a positive number can't overflow.
+    let non_positive = |n: T| if n > T::ZERO { -n } else { n };
+    let (mut a, mut b) = (non_positive(a), non_positive(b));
+    while b != T::ZERO {
+        // the remainder only overflows for `T::MIN % -1`, which is 0.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    a.checked_neg()
+}
+
+/// the least common multiple, never negative. `None` if it overflows.
+pub fn lcm<T: SignedInt>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// the greatest common divisor of all numbers, 0 for none. `None` if it overflows.
+pub fn gcd_all<T: SignedInt>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ZERO, gcd)
+}
+
+/// the least common multiple of all numbers, 1 for none. `None` if it overflows,
+/// e.g. when all cycles in a puzzle line up again.
+pub fn lcm_all<T: SignedInt>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, |acc, n| lcm(acc, n))
+}
+
+/// `(g, x, y)` with `a * x + b * y = g`, where `g` is the greatest common divisor of
+/// `a` and `b`, possibly negative. `None` if it overflows, e.g. for `extended_gcd(T::MIN, -1)`.
+pub fn extended_gcd<T: SignedInt>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r.checked_div(r)?;
+        let next_r = old_r.checked_sub(q.checked_mul(r)?)?;
+        if next_r == T::ZERO {
+            // the next coefficients are `±b / g` and `±a / g`, which may not fit into `T`.
+            return Some((r, x, y));
+        }
+        (old_r, r) = (r, next_r);
+        (old_x, x) = (x, old_x.checked_sub(q.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(q.checked_mul(y)?)?);
+    }
+    Some((old_r, old_x, old_y))
+}
+
+/// `a * b % m` in `0..m`, without overflowing even if `a * b` does not fit into `T`.
+/// panics if `m` is not positive.
+pub fn mul_mod<T: Int>(a: T, b: T, m: T) -> T {
+    assert!(m > T::ZERO, "modulus {:?} is not positive", m);
+    let (a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    // double and add, all intermediate values stay below `2 * m`.
+    let two = T::ONE + T::ONE;
+    let (mut result, mut power) = (T::ZERO, a);
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, power, m);
+        }
+        power = add_mod(power, power, m);
+        b = b / two;
+    }
+    result
+}
+
+/// `a + b` in `0..m` for `a` and `b` in `0..m`.
+fn add_mod<T: Int>(a: T, b: T, m: T) -> T {
+    // `a - (m - b)` avoids overflowing `a + b`.
+    let rest = m - b;
+    if a >= rest {
+        a - rest
+    } else {
+        a + b
+    }
+}
+
+/// `base ^ exp % m` in `0..m`. panics if `exp` is negative or `m` is not positive.
+pub fn mod_pow<T: Int>(base: T, exp: T, m: T) -> T {
+    assert!(exp >= T::ZERO, "negative exponent {:?}", exp);
+    assert!(m > T::ZERO, "modulus {:?} is not positive", m);
+    let two = T::ONE + T::ONE;
+    let (mut result, mut base, mut exp) = (T::ONE % m, base.rem_euclid(m), exp);
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+    result
+}
+
+/// the `x` in `0..m` with `a * x % m == 1`, `None` if `a` and `m` are not coprime or `m` is
+/// not positive.
+pub fn mod_inverse<T: SignedInt>(a: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m)?;
+    (g == T::ONE || g == -T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// solves the system of congruences `x ≡ residue (mod modulus)` with the chinese remainder
+/// theorem. the moduli don't need to be coprime. returns the smallest non-negative `x` and the
+/// modulus of the combined congruence, i.e. the least common multiple of all moduli, or `None`
+/// if a modulus is not positive, there is no solution or it overflows.
+pub fn crt<T: SignedInt>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(x, m), (r, n)| {
+            if n <= T::ZERO {
+                return None;
+            }
+            let r = r.rem_euclid(n);
+            // x + m * k ≡ r (mod n)  <=>  (m / g) * k ≡ (r - x) / g (mod n / g)
+            let (g, inverse, _) = extended_gcd(m, n)?;
+            let diff = (r - x).rem_euclid(n);
+            if diff % g != T::ZERO {
+                return None;
+            }
+            let n_g = n / g;
+            let k = mul_mod(diff / g, inverse, n_g);
+            let combined = lcm(m, n)?;
+            let x = m.checked_mul(k)?.checked_add(x)?.rem_euclid(combined);
+            Some((x, combined))
+        })
+}
+
+/// the largest `r` with `r * r <= n`. panics if `n` is negative.
+pub fn isqrt<T: Int>(n: T) -> T {
+    assert!(n >= T::ZERO, "square root of negative number {:?}", n);
+    if n <= T::ONE {
+        return n;
+    }
+    // newton's method, starting above the root and decreasing monotonically.
+    let two = T::ONE + T::ONE;
+    let mut x = n / two + T::ONE;
+    let mut y = (x + n / x) / two;
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a small xorshift generator, so the property tests are reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// a number in `-limit..=limit`.
+        fn signed(&mut self, limit: i64) -> i64 {
+            (self.next() % (2 * limit as u64 + 1)) as i64 - limit
+        }
+
+        /// a number in `1..=limit`.
+        fn positive(&mut self, limit: i64) -> i64 {
+            (self.next() % limit as u64) as i64 + 1
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(gcd(12_i64, -18), Some(6));
+        assert_eq!(gcd(0_i64, 0), Some(0));
+        assert_eq!(lcm(4_i64, 6), Some(12));
+        assert_eq!(lcm_all([2_i64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<i64>::new()), Some(1));
+        assert_eq!(lcm(i64::MAX, i64::MAX - 1), None);
+        assert_eq!(gcd_all([12_i128, 30, 42]), Some(6));
+        assert_eq!(mod_inverse(3_i64, 11), Some(4));
+        assert_eq!(mod_inverse(4_i64, 8), None);
+        assert_eq!(mod_inverse(3_i64, 0), None);
+        assert_eq!(mod_inverse(3_i64, -11), None);
+        assert_eq!(mod_pow(2_i64, 10, 1000), 24);
+        assert_eq!(mod_pow(5_i64, 0, 1), 0);
+        assert_eq!(crt([(0_i64, 3), (3, 4), (4, 5)]), Some((39, 60)));
+        assert_eq!(crt([(1_i64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1_i64, 4), (2, 6)]), None);
+        assert_eq!(crt([(1_i64, -3)]), None);
+        assert_eq!(crt([(1_i64, 4), (0, 0)]), None);
+        assert_eq!(isqrt(0_i64), 0);
+        assert_eq!(isqrt(15_i64), 3);
+        assert_eq!(isqrt(16_i64), 4);
+        assert_eq!(isqrt(i64::MAX), 3_037_000_499);
+        assert_eq!(isqrt(i128::MAX), 13_043_817_825_332_782_212);
+    }
+
+    #[test]
+    fn test_min() {
+        let min = i64::MIN;
+        assert_eq!(gcd(min, 2), Some(2));
+        assert_eq!(gcd(6, min), Some(2));
+        assert_eq!(gcd(min, -1), Some(1));
+        assert_eq!(gcd(min, 0), None);
+        assert_eq!(gcd(min, min), None);
+        assert_eq!(gcd_all([min, 0]), None);
+        assert_eq!(gcd_all([4, min, 6]), Some(2));
+        assert_eq!(lcm(min, 0), Some(0));
+        assert_eq!(lcm(min, 2), None);
+        assert_eq!(lcm(min / 2, 2), Some(1 << 62));
+        assert_eq!(lcm_all([3, min]), None);
+        assert_eq!(gcd(i128::MIN, i128::MIN / 3 * 3), Some(2));
+        assert_eq!(extended_gcd(min, -1), None);
+        assert_eq!(mod_inverse(min, -1), None);
+        assert_eq!(mod_inverse(min, 3), Some(1));
+        assert_eq!(extended_gcd(min, 0), Some((min, 1, 0)));
+        assert_eq!(extended_gcd(min, 2), Some((2, 0, 1)));
+        assert_eq!(extended_gcd(3, min), Some((1, 3074457345618258603, 1)));
+    }
+
+    #[test]
+    fn test_no_overflow_near_max() {
+        let m = i64::MAX - 24; // a large prime.
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(mod_pow(2, m - 1, m), 1); // fermat's little theorem.
+        let inverse = mod_inverse(123_456_789, m).unwrap();
+        assert_eq!(mul_mod(inverse, 123_456_789, m), 1);
+        let m = i128::MAX;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus 0 is not positive")]
+    fn test_mul_mod_zero() {
+        mul_mod(3_i64, 4, 0);
+    }
+
+    #[test]
+    fn test_gcd_properties() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..1000 {
+            let (a, b) = (rng.signed(1_000_000), rng.signed(1_000_000));
+            let g = gcd(a, b).unwrap();
+            assert!(g >= 0);
+            if g != 0 {
+                assert_eq!(a % g, 0);
+                assert_eq!(b % g, 0);
+                assert_eq!(gcd(a / g, b / g), Some(1));
+                let l = lcm(a, b).unwrap();
+                assert_eq!(l % a.abs().max(1), 0);
+                assert_eq!(g * l, (a * b).abs());
+            }
+            let (e, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(e.abs(), g);
+            assert_eq!(a * x + b * y, e);
+
+            // `i64::MIN` is `-2^63`, so only powers of two divide it, and every multiple of it
+            // other than 0 is out of range.
+            let b = b.max(1) << (rng.next() % 32);
+            assert_eq!(gcd(i64::MIN, b), Some(1 << b.trailing_zeros()));
+            assert_eq!(gcd(-b, i64::MIN), Some(1 << b.trailing_zeros()));
+            assert_eq!(lcm(i64::MIN, b), None);
+        }
+    }
+
+    #[test]
+    fn test_modular_properties() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let m = rng.positive(10_000);
+            let a = rng.signed(1_000_000);
+            let e = rng.positive(50) - 1;
+            let naive = (0..e).fold(1 % m, |acc, _| (acc * a).rem_euclid(m));
+            assert_eq!(mod_pow(a, e, m), naive);
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    assert!((0..m).contains(&inverse));
+                    assert_eq!(mul_mod(a, inverse, m), 1 % m);
+                }
+                None => assert_ne!(gcd(a, m), Some(1)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_properties() {
+        let mut rng = Rng(0xdead_beef_cafe_f00d);
+        for _ in 0..1000 {
+            let congruences: Vec<(i64, i64)> = (0..rng.positive(4))
+                .map(|_| (rng.signed(1000), rng.positive(12)))
+                .collect();
+            let solution = crt(congruences.iter().copied());
+            // brute force over one period of all moduli.
+            let period = lcm_all(congruences.iter().map(|&(_, n)| n)).unwrap();
+            let expected =
+                (0..period).find(|x| congruences.iter().all(|&(r, n)| (x - r).rem_euclid(n) == 0));
+            assert_eq!(solution, expected.map(|x| (x, period)), "{:?}", congruences);
+        }
+    }
+
+    #[test]
+    fn test_isqrt_properties() {
+        let mut rng = Rng(0x0123_4567_89ab_cdef);
+        for _ in 0..1000 {
+            let n = (rng.next() >> 1) as i64 >> (rng.next() % 63);
+            let r = isqrt(n);
+            assert!(r * r <= n);
+            assert!((r + 1).checked_mul(r + 1).is_none_or(|s| s > n));
+        }
+    }
+}
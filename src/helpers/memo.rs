@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// the function memoized by [`Memo`], `recurse` looks up or computes the value of another key.
+type Recursive<'f, K, V> = dyn Fn(&mut dyn FnMut(K) -> V, &K) -> V + 'f;
+
+/// how often a [`Memo`] could answer from its cache. only counted in debug builds, so
+/// release builds always report zeros.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+/// a cache for a recursive function, e.g.
+///
+/// ```
+/// use advent_of_code::helpers::Memo;
+///
+/// let mut memo = Memo::new();
+/// let fib = memo.solve(80_u64, |fib, &n| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+/// assert_eq!(fib, 23_416_728_348_467_685);
+/// ```
+///
+/// values are cached by key only, so use one memo per function and [`Memo::clear`] it
+/// if the function captures something that changes, e.g. between parts.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// the value of `f` for `key`. `f` is called with a function to recurse into other keys
+    /// and only for keys that are not cached yet.
+    pub fn solve<F>(&mut self, key: K, f: F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, &K) -> V,
+    {
+        self.get_or_compute(key, &f)
+    }
+
+    fn get_or_compute(&mut self, key: K, f: &Recursive<'_, K, V>) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            #[cfg(debug_assertions)]
+            {
+                self.stats.hits += 1;
+            }
+            return value.clone();
+        }
+        #[cfg(debug_assertions)]
+        {
+            self.stats.misses += 1;
+        }
+        let value = f(&mut |k| self.get_or_compute(k, f), &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// the cached value for `key`, without computing it.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// forgets all cached values and resets the stats.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = MemoStats::default();
+    }
+
+    /// the cache hits and misses so far, all zero in release builds.
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recursion() {
+        let mut memo = Memo::new();
+        let fib = |fib: &mut dyn FnMut(u64) -> u64, &n: &u64| {
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        };
+        assert_eq!(memo.solve(90, fib), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 91);
+        assert_eq!(memo.get(&10), Some(&55));
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 88,
+                misses: 91
+            }
+        );
+
+        assert_eq!(memo.solve(90, fib), 2_880_067_194_370_816_120);
+        #[cfg(debug_assertions)]
+        assert_eq!(memo.stats().hits, 89);
+        #[cfg(not(debug_assertions))]
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+
+    #[test]
+    fn test_captures_and_clear() {
+        // the number of ways to climb `n` stairs with the given step sizes.
+        let ways = |steps: &'static [usize]| {
+            move |ways: &mut dyn FnMut(usize) -> u64, &n: &usize| {
+                if n == 0 {
+                    1
+                } else {
+                    steps.iter().filter(|&&s| s <= n).map(|s| ways(n - s)).sum()
+                }
+            }
+        };
+        let mut memo = Memo::new();
+        assert_eq!(memo.solve(10, ways(&[1, 2])), 89);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), MemoStats::default());
+        assert_eq!(memo.solve(10, ways(&[1, 2, 3])), 274);
+    }
+}
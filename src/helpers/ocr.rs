@@ -0,0 +1,253 @@
+/*
+ * Decodes the block letters that some puzzles draw as their answer.
+ * Glyphs are separated by at least one empty column, so they are stored without surrounding
+ * empty columns and `I` or `Y` may be narrower or wider than the other letters.
+ */
+
+/// the 4x6 font, e.g. used in 2016 day 8, 2019 day 8 and 11, 2021 day 13 and 2022 day 10.
+const FONT_6: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// the 6x10 font, e.g. used in 2018 day 10.
+const FONT_10: [(char, [&str; 10]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+fn is_lit(c: char) -> bool {
+    !c.is_whitespace() && c != '.'
+}
+
+fn lookup(glyph: &[String]) -> Option<char> {
+    let matches = |rows: &[&str]| rows.iter().zip(glyph).all(|(a, b)| *a == b);
+    match glyph.len() {
+        6 => FONT_6
+            .iter()
+            .find(|(_, rows)| matches(rows))
+            .map(|(c, _)| *c),
+        10 => FONT_10
+            .iter()
+            .find(|(_, rows)| matches(rows))
+            .map(|(c, _)| *c),
+        _ => None,
+    }
+}
+
+/// decodes a picture of block letters, 6 or 10 rows high, into a string.
+/// `.` and whitespace are read as unlit pixels, anything else as lit ones.
+/// returns `None` if the picture has a different height or contains an unknown glyph.
+pub fn recognize(picture: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = picture
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.chars().map(is_lit).collect())
+        .collect();
+    if rows.len() != 6 && rows.len() != 10 {
+        return None;
+    }
+
+    let width = rows.iter().map(|r| r.len()).max()?;
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let column_is_empty = |x: usize| (0..rows.len()).all(|y| !lit(x, y));
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if column_is_empty(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !column_is_empty(x) {
+            x += 1;
+        }
+        let glyph: Vec<String> = (0..rows.len())
+            .map(|y| {
+                (start..x)
+                    .map(|gx| if lit(gx, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        text.push(lookup(&glyph)?);
+    }
+
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize_4x6() {
+        let picture = [
+            "#..#..###..####.###..",
+            "#..#...#......#.#..#.",
+            "####...#.....#..#..#.",
+            "#..#...#....#...###..",
+            "#..#...#...#....#.#..",
+            "#..#..###..####.#..#.",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&picture), Some("HIZR".to_string()));
+        assert_eq!(
+            recognize(&picture.replace('.', " ").replace('#', "█")),
+            Some("HIZR".to_string())
+        );
+    }
+
+    #[test]
+    fn test_recognize_6x10() {
+        let picture = [
+            "#....#.....###..#....#..#....#..######...##...",
+            "#....#......#...##...#..#....#.......#..#..#..",
+            "#....#......#...##...#...#..#........#.#....#.",
+            "#....#......#...#.#..#...#..#.......#..#....#.",
+            "######......#...#.#..#....##.......#...#....#.",
+            "#....#......#...#..#.#....##......#....######.",
+            "#....#......#...#..#.#...#..#....#.....#....#.",
+            "#....#..#...#...#...##...#..#...#......#....#.",
+            "#....#..#...#...#...##..#....#..#......#....#.",
+            "#....#...###....#....#..#....#..######.#....#.",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&picture), Some("HJNXZA".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_unknown() {
+        let sprite = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&sprite), None);
+        assert_eq!(recognize("#..#\n####"), None);
+        assert_eq!(recognize(""), None);
+    }
+}
@@ -0,0 +1,453 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// a line of puzzle input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column of the first character of `text`.
+    pub column: usize,
+    pub text: String,
+    pub type_name: &'static str,
+    /// the error reported by the parser, if it said anything.
+    pub reason: Option<String>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: cannot parse \"{}\" as {}",
+            self.line, self.text, self.type_name
+        )?;
+        if let Some(reason) = &self.reason {
+            write!(f, " ({})", reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// strips the module path from a type name, e.g. `advent_of_code::Instruction` -> `Instruction`.
+/// generic types with paths in their arguments are returned as they are.
+pub(crate) fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let path_end = name.find('<').unwrap_or(name.len());
+    match name[..path_end].rfind("::") {
+        Some(i) if !name[path_end..].contains("::") => &name[i + 2..],
+        _ => name,
+    }
+}
+
+/// parses a single line, `text` is expected to be trimmed already.
+pub(crate) fn parse_line<T>(text: &str, line: usize, column: usize) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse().map_err(|e: T::Err| {
+        let reason = e.to_string();
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            type_name: short_type_name::<T>(),
+            reason: (!reason.is_empty()).then_some(reason),
+        }
+    })
+}
+
+/// parses every non-empty line of `input` as `T`.
+/// surrounding whitespace is trimmed, so indented inputs in tests parse as well.
+pub fn try_parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let text = l.trim_start();
+            let column = l[..l.len() - text.len()].chars().count() + 1;
+            parse_line(text.trim_end(), i + 1, column)
+        })
+        .collect()
+}
+
+/// like [`try_parse_lines`], but panics with the position of the first bad line.
+pub fn parse_lines<T>(input: &str) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    try_parse_lines(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    /// `-` is never part of a number.
+    Never,
+    /// `-` is part of a number unless it directly follows a letter or digit, as in `2-4`.
+    Auto,
+    /// `-` directly in front of digits is always part of the number.
+    Always,
+}
+
+/// iterator returned by [`ints`], [`signed_ints`] and [`unsigned_ints`].
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    line: &'a str,
+    pos: usize,
+    sign: Sign,
+    /// whether a number did not fit into `T`.
+    failed: bool,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> Ints<'a, T> {
+    /// whether the iterator stopped early because a number did not fit into `T`.
+    pub fn failed(&self) -> bool {
+        self.failed
+    }
+}
+
+impl<'a, T: FromStr> Iterator for Ints<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let bytes = self.line.as_bytes();
+        let digits_start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let digits_end = digits_start
+            + bytes[digits_start..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - digits_start);
+        self.pos = digits_end;
+
+        let has_minus = digits_start > 0 && bytes[digits_start - 1] == b'-';
+        let is_negative = has_minus
+            && match self.sign {
+                Sign::Never => false,
+                Sign::Always => true,
+                Sign::Auto => digits_start < 2 || !bytes[digits_start - 2].is_ascii_alphanumeric(),
+            };
+        let start = if is_negative {
+            digits_start - 1
+        } else {
+            digits_start
+        };
+
+        match self.line[start..digits_end].parse() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                // stop for good, the remaining numbers would be misaligned.
+                self.failed = true;
+                self.pos = bytes.len();
+                None
+            }
+        }
+    }
+}
+
+fn ints_with_sign<T>(line: &str, sign: Sign) -> Ints<'_, T> {
+    Ints {
+        line,
+        pos: 0,
+        sign,
+        failed: false,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+/// extracts all integers from `line`, e.g. `[3, 1, 2]` from `move 3 from 1 to 2`.
+/// a `-` counts as a sign unless it directly follows a letter or digit, so `x=-3` yields `-3`
+/// while the range `2-4` yields `2` and `4`. stops at the first number that does not fit into
+/// `T`, such as `-3` for an unsigned type or an overflow, see [`Ints::failed`].
+pub fn ints<T>(line: &str) -> Ints<'_, T> {
+    ints_with_sign(line, Sign::Auto)
+}
+
+/// like [`ints`], but every `-` in front of a number is treated as its sign.
+pub fn signed_ints<T>(line: &str) -> Ints<'_, T> {
+    ints_with_sign(line, Sign::Always)
+}
+
+/// like [`ints`], but `-` is never treated as a sign.
+pub fn unsigned_ints<T>(line: &str) -> Ints<'_, T> {
+    ints_with_sign(line, Sign::Never)
+}
+
+/// extracts exactly `N` integers from `line`, see [`ints`].
+/// returns `None` if `line` contains fewer or more numbers, or one does not fit into `T`.
+pub fn ints_n<T: FromStr, const N: usize>(line: &str) -> Option<[T; N]> {
+    let mut numbers = ints(line);
+    let values: [Option<T>; N] = std::array::from_fn(|_| numbers.next());
+    if numbers.next().is_some() || numbers.failed() || values.iter().any(|v| v.is_none()) {
+        return None;
+    }
+    Some(values.map(|v| v.unwrap()))
+}
+
+/// reads a fixed-width drawing of stacked columns, such as
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+///
+/// into one `(label, items)` pair per label of the last line, from left to right.
+/// items are ordered bottom to top. a column's item is the first character below its label
+/// that is neither whitespace nor a bracket, so multi-digit labels such as `10` work as well.
+pub fn parse_columns(drawing: &str) -> Vec<(&str, Vec<char>)> {
+    let mut lines: Vec<&str> = drawing.lines().filter(|l| !l.trim().is_empty()).collect();
+    let label_line = match lines.pop() {
+        Some(label_line) => label_line,
+        None => return vec![],
+    };
+
+    // char spans of each label, e.g. `10` at chars 37..39.
+    let mut columns: Vec<(&str, usize, usize)> = Vec::new();
+    let mut label_start = None;
+    for (i, (byte, c)) in label_line
+        .char_indices()
+        .chain(std::iter::once((label_line.len(), ' ')))
+        .enumerate()
+    {
+        match (c.is_whitespace(), label_start) {
+            (false, None) => label_start = Some((i, byte)),
+            (true, Some((start, start_byte))) => {
+                columns.push((&label_line[start_byte..byte], start, i));
+                label_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut stacks: Vec<Vec<char>> = vec![Vec::with_capacity(lines.len()); columns.len()];
+    let mut chars = Vec::new();
+    for line in lines.iter().rev() {
+        chars.clear();
+        chars.extend(line.chars());
+        for (stack, (_, start, end)) in stacks.iter_mut().zip(columns.iter()) {
+            let item = chars
+                .get(*start..(*end).min(chars.len()))
+                .and_then(|cells| {
+                    cells
+                        .iter()
+                        .find(|c| !c.is_whitespace() && !matches!(c, '[' | ']'))
+                });
+            if let Some(item) = item {
+                stack.push(*item);
+            }
+        }
+    }
+
+    columns
+        .into_iter()
+        .map(|(label, _, _)| label)
+        .zip(stacks)
+        .collect()
+}
+
+/// splits `input` into sections separated by one or more blank lines, together with the
+/// 1-based line number each section starts at. leading and trailing blank lines are ignored.
+fn numbered_blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut blocks = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for (i, l) in input.split_inclusive('\n').enumerate() {
+        let line = l.trim_end_matches(['\n', '\r']);
+        if line.trim().is_empty() {
+            if let Some((line_number, block_start)) = start.take() {
+                blocks.push((line_number, &input[block_start..end]));
+            }
+        } else {
+            start.get_or_insert((i + 1, offset));
+            end = offset + line.len();
+        }
+        offset += l.len();
+    }
+    if let Some((line_number, block_start)) = start {
+        blocks.push((line_number, &input[block_start..end]));
+    }
+    blocks.into_iter()
+}
+
+/// splits `input` into sections separated by one or more blank lines.
+/// handles `\r\n` line endings, and leading and trailing blank lines are ignored.
+/// indentation within a section is kept.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    numbered_blocks(input).map(|(_, block)| block)
+}
+
+/// parses every line of every section of `input` as `T`, see [`blocks`].
+pub fn try_parse_blocks<T>(input: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    numbered_blocks(input)
+        .map(|(first_line, block)| {
+            try_parse_lines(block).map_err(|e| ParseError {
+                line: e.line + first_line - 1,
+                ..e
+            })
+        })
+        .collect()
+}
+
+/// like [`try_parse_blocks`], but panics with the position of the first bad line.
+pub fn parse_blocks<T>(input: &str) -> Vec<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    try_parse_blocks(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Instruction;
+
+    impl FromStr for Instruction {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "noop" => Ok(Instruction),
+                _ => Err(String::new()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<u32>("1\n\n  2  \n3"), vec![1, 2, 3]);
+        assert_eq!(parse_lines::<i64>("-1\r\n2\r\n"), vec![-1, 2]);
+        assert_eq!(parse_lines::<Instruction>("noop\n"), vec![Instruction]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_parse_lines::<Instruction>("noop\n\n    adx 3\nnoop").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(
+            err.to_string(),
+            "line 3: cannot parse \"adx 3\" as Instruction"
+        );
+
+        let err = try_parse_lines::<u8>("1\n300").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: cannot parse \"300\" as u8 (number too large to fit in target type)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "line 1: cannot parse \"x\" as u32")]
+    fn test_parse_lines_panics() {
+        parse_lines::<u32>("x");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n1\n2\n\n\n  3\n4\n\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["1\n2", "  3\n4"]);
+        let input = "1\r\n2\r\n\r\n3\r\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["1\r\n2", "3"]);
+        assert_eq!(blocks("1\n   \n2").collect::<Vec<_>>(), vec!["1", "2"]);
+        assert_eq!(blocks("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        assert_eq!(
+            parse_blocks::<u32>("1\n2\n\n3\n"),
+            vec![vec![1, 2], vec![3]]
+        );
+        let err = try_parse_blocks::<u32>("1\n2\n\n3\nx\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5: cannot parse \"x\" as u32 (invalid digit found in string)"
+        );
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<usize>("move 13 from 1 to 2").collect::<Vec<_>>(),
+            vec![13, 1, 2]
+        );
+        assert_eq!(ints::<u16>("2-4,6-8").collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+        assert_eq!(
+            ints::<i32>("x=-3, y=12..-7 -1").collect::<Vec<_>>(),
+            vec![-3, 12, -7, -1]
+        );
+        assert_eq!(signed_ints::<i32>("2-4").collect::<Vec<_>>(), vec![2, -4]);
+        assert_eq!(unsigned_ints::<u8>("x=-3").collect::<Vec<_>>(), vec![3]);
+        assert_eq!(ints::<u8>("no numbers").next(), None);
+    }
+
+    #[test]
+    fn test_ints_invalid() {
+        let mut numbers = ints::<u8>("a 3 300 4");
+        assert_eq!(numbers.by_ref().collect::<Vec<_>>(), vec![3]);
+        assert!(numbers.failed());
+        let mut numbers = ints::<u32>("x=-3, y=4");
+        assert_eq!(numbers.next(), None);
+        assert!(numbers.failed());
+        assert!(!ints::<u32>("x=3").failed());
+    }
+
+    #[test]
+    fn test_ints_n() {
+        assert_eq!(ints_n::<usize, 3>("move 3 from 1 to 2"), Some([3, 1, 2]));
+        assert_eq!(ints_n::<usize, 3>("move 3 from 1"), None);
+        assert_eq!(ints_n::<usize, 2>("move 3 from 1 to 2"), None);
+        assert_eq!(ints_n::<u8, 2>("1 300"), None);
+        assert_eq!(ints_n::<u32, 2>("1 -3"), None);
+        assert_eq!(ints_n::<i32, 2>("1 -3"), Some([1, -3]));
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+        assert_eq!(
+            parse_columns(drawing),
+            vec![
+                ("1", vec!['Z', 'N']),
+                ("2", vec!['M', 'C', 'D']),
+                ("3", vec!['P'])
+            ]
+        );
+
+        let drawing = [
+            "                                    [K]",
+            "[A] [B] [C] [D] [E] [F] [G] [H] [I] [J]",
+            " 1   2   3   4   5   6   7   8   9   10",
+        ]
+        .join("\n");
+        let columns = parse_columns(&drawing);
+        assert_eq!(columns.len(), 10);
+        assert_eq!(columns[9], ("10", vec!['J', 'K']));
+        assert!(parse_columns("").is_empty());
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<Instruction>(), "Instruction");
+        assert_eq!(short_type_name::<u8>(), "u8");
+        assert_eq!(short_type_name::<Vec<u32>>(), "Vec<u32>");
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::direction::{Dir8, Direction};
+use super::math::SignedInt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: SignedInt> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// the number of king moves between two points.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// the sign of each coordinate, i.e. a step of at most one towards `self` from the origin.
+    pub fn signum(&self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn step(&self, dir: Direction) -> Self {
+        *self + dir.offset()
+    }
+
+    pub fn step8(&self, dir: Dir8) -> Self {
+        *self + dir.offset()
+    }
+
+    /// the four orthogonally adjacent points, clockwise starting with north.
+    pub fn neighbours4(&self) -> [Self; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    /// the eight orthogonally and diagonally adjacent points, clockwise starting with north.
+    pub fn neighbours8(&self) -> [Self; 8] {
+        Dir8::ALL.map(|dir| self.step8(dir))
+    }
+}
+
+impl<T: SignedInt> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// the six points that share a face with this one.
+    pub fn neighbours6(&self) -> [Self; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Point3::new(-one, zero, zero),
+            Point3::new(one, zero, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, zero, -one),
+            Point3::new(zero, zero, one),
+        ]
+        .map(|d| *self + d)
+    }
+
+    /// the 26 points that share a face, edge or corner with this one.
+    pub fn neighbours26(&self) -> Vec<Self> {
+        let units = [-T::ONE, T::ZERO, T::ONE];
+        let mut neighbours = Vec::with_capacity(26);
+        for dx in units {
+            for dy in units {
+                for dz in units {
+                    if dx != T::ZERO || dy != T::ZERO || dz != T::ZERO {
+                        neighbours.push(*self + Point3::new(dx, dy, dz));
+                    }
+                }
+            }
+        }
+        neighbours
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $point { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $point { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $point { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut a = Point2::new(1, 2);
+        let b = Point2::new(3, -4);
+        assert_eq!(a + b, Point2::new(4, -2));
+        assert_eq!(a - b, Point2::new(-2, 6));
+        assert_eq!(b * 2, Point2::new(6, -8));
+        assert_eq!(-a, Point2::new(-1, -2));
+        a += b;
+        a -= Point2::new(1, 1);
+        assert_eq!(a, Point2::new(3, -3));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::from((1, 1, 1)),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1_i32, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, -1));
+        let c = Point3::new(0_i64, 0, 0);
+        let d = Point3::new(-1, 5, 2);
+        assert_eq!(c.manhattan(&d), 8);
+        assert_eq!(c.chebyshev(&d), 5);
+        assert_eq!(d.signum(), Point3::new(-1, 1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(0_i64, 0);
+        assert_eq!(
+            p.neighbours4(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)].map(Point2::from)
+        );
+        assert!(p.neighbours8().iter().all(|n| n.chebyshev(&p) == 1));
+        assert_eq!(p.step(Direction::West), Point2::new(-1, 0));
+        let q = Point3::new(1_i32, 1, 1);
+        assert!(q.neighbours6().iter().all(|n| n.manhattan(&q) == 1));
+        assert_eq!(q.neighbours26().len(), 26);
+        assert!(q.neighbours26().iter().all(|n| n.chebyshev(&q) == 1));
+    }
+}
@@ -0,0 +1,275 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::grid::Grid;
+use super::math::Int;
+
+/// follows the parent links from `index` back to the start node.
+fn reconstruct_path<N: Clone>(nodes: &[(N, usize)], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].0.clone()];
+    while nodes[index].1 != index {
+        index = nodes[index].1;
+        path.push(nodes[index].0.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// the shortest path from `start` to the first node accepted by `success`,
+/// counting every step as one. returns the path including `start` and the number of steps.
+pub fn bfs<N, FN, IN, FS>(start: N, mut successors: FN, mut success: FS) -> Option<(Vec<N>, usize)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    // every node with the index of its parent, the start node is its own parent.
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut seen = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if success(&nodes[index].0) {
+            return Some((reconstruct_path(&nodes, index), steps));
+        }
+        for next in successors(&nodes[index].0) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(nodes.len());
+                queue.push_back((nodes.len(), steps + 1));
+                nodes.push((next, index));
+            }
+        }
+    }
+    None
+}
+
+/// the cheapest path from `start` to the first node accepted by `success`.
+/// `successors` yields the neighbours of a node with the cost of moving there, which must not
+/// be negative. returns the path including `start` and its total cost.
+pub fn dijkstra<N, C, FN, IN, FS>(start: N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Int,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::ZERO, success)
+}
+
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// reversed, so the max-heap pops the lowest estimate first. ties prefer the higher cost,
+/// i.e. the candidate closer to the goal.
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// like [`dijkstra`], but explores nodes in order of their cost plus `heuristic`.
+/// the heuristic must never overestimate the remaining cost for the result to be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Int,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    // every node with the index of its parent and the cheapest known cost to reach it.
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut costs = vec![C::ZERO];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: C::ZERO,
+        index: 0,
+    }]);
+
+    while let Some(Candidate { cost, index, .. }) = heap.pop() {
+        if cost > costs[index] {
+            continue; // a cheaper way to this node was found after it was queued.
+        }
+        if success(&nodes[index].0) {
+            return Some((reconstruct_path(&nodes, index), cost));
+        }
+        for (next, step) in successors(&nodes[index].0) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push((entry.key().clone(), index));
+                    costs.push(next_cost);
+                    entry.insert(next_index);
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if costs[next_index] <= next_cost {
+                        continue;
+                    }
+                    nodes[next_index].1 = index;
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+            };
+            heap.push(Candidate {
+                estimate: next_cost + heuristic(&nodes[next_index].0),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+/// a grid position as used by [`Grid::get`].
+type Position = (i64, i64);
+
+/// successors for 4-neighbour movement on `grid`, to be used with [`bfs`].
+/// `can_move(from, to)` decides whether a step between two cells is allowed.
+pub fn grid_moves4<'a, T>(
+    grid: &'a Grid<T>,
+    can_move: impl Fn(&T, &T) -> bool + 'a,
+) -> impl Fn(&Position) -> Vec<Position> + 'a {
+    move |&(x, y)| match grid.get(x, y) {
+        Some(from) => grid
+            .neighbours4(x, y)
+            .filter(|(_, to)| can_move(from, to))
+            .map(|(position, _)| position)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// weighted successors for 4-neighbour movement on `grid`, to be used with [`dijkstra`] or
+/// [`astar`]. `cost(from, to)` returns the cost of a step, or `None` if it is not allowed.
+pub fn grid_weighted4<'a, T, C>(
+    grid: &'a Grid<T>,
+    cost: impl Fn(&T, &T) -> Option<C> + 'a,
+) -> impl Fn(&Position) -> Vec<(Position, C)> + 'a {
+    move |&(x, y)| match grid.get(x, y) {
+        Some(from) => grid
+            .neighbours4(x, y)
+            .filter_map(|(position, to)| Some((position, cost(from, to)?)))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //  a --1-- b --1-- c
+    //  |               |
+    //  5               1
+    //  |               |
+    //  e ------1------ d --7-- f
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('e', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('d', 1)],
+            'd' => vec![('c', 1), ('e', 1), ('f', 7)],
+            'e' => vec![('a', 5), ('d', 1)],
+            'f' => vec![('d', 7)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        graph(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(
+            bfs('a', unweighted, |n| *n == 'd'),
+            Some((vec!['a', 'e', 'd'], 2))
+        );
+        assert_eq!(bfs('a', unweighted, |n| *n == 'a'), Some((vec!['a'], 0)));
+        assert_eq!(bfs('a', unweighted, |n| *n == 'x'), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra('a', graph, |n| *n == 'e'),
+            Some((vec!['a', 'b', 'c', 'd', 'e'], 4))
+        );
+        assert_eq!(
+            dijkstra('e', graph, |n| *n == 'f'),
+            Some((vec!['e', 'd', 'f'], 8))
+        );
+        assert_eq!(dijkstra('a', graph, |n| *n == 'x'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // the number of edges to `f` never overestimates the cost, as every edge costs at least 1.
+        let edges_to_f = |n: &char| match n {
+            'f' => 0,
+            'd' => 1,
+            'c' | 'e' => 2,
+            'b' | 'a' => 3,
+            _ => u32::MAX,
+        };
+        assert_eq!(
+            astar('a', graph, edges_to_f, |n| *n == 'f'),
+            Some((vec!['a', 'b', 'c', 'd', 'f'], 10))
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse_chars("S.#.\n.##.\n...E").unwrap();
+        let open = |_: &char, to: &char| *to != '#';
+        let (path, steps) = bfs((0, 0), grid_moves4(&grid, open), |p| *p == (3, 2)).unwrap();
+        assert_eq!(steps, 5);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(3, 2)));
+
+        let digits = Grid::parse_digits("131\n191\n111").unwrap();
+        let (path, cost) = astar(
+            (0, 0),
+            grid_weighted4(&digits, |_, to| Some(*to)),
+            |&(x, y)| (2 - x + 2 - y) as u32,
+            |p| *p == (2, 2),
+        )
+        .unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+}
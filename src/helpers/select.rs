@@ -0,0 +1,135 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// the `k` largest items, largest first, in O(n log k) while keeping only `k` items around.
+fn largest<T: Ord>(items: impl Iterator<Item = T>, k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+    // a min-heap of the largest items so far, so the smallest of them can be dropped.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for item in items {
+        heap.push(Reverse(item));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    // sorting the reversed items ascending puts the largest first.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+/// an item ordered by its key only, ties are broken by `rank`.
+struct Keyed<K, T> {
+    key: K,
+    rank: Reverse<usize>,
+    item: T,
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.key, self.rank).cmp(&(&other.key, other.rank))
+    }
+}
+
+/// the `k` largest items, largest first. of equal items, the earlier ones are kept.
+/// runs in O(n log k), e.g. `top_k(sums, 3)` for the three largest sums.
+pub fn top_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let ranked = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| (item, Reverse(i)));
+    largest(ranked, k)
+        .into_iter()
+        .map(|(item, _)| item)
+        .collect()
+}
+
+/// the `k` smallest items, smallest first, see [`top_k`].
+pub fn bottom_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let ranked = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| (Reverse(item), Reverse(i)));
+    largest(ranked, k)
+        .into_iter()
+        .map(|(Reverse(item), _)| item)
+        .collect()
+}
+
+/// the `k` items with the largest keys, largest first, like a `max_by_key` for `k` items.
+/// of items with equal keys, the earlier ones are kept.
+pub fn top_k_by_key<T, K: Ord>(
+    items: impl IntoIterator<Item = T>,
+    k: usize,
+    mut key: impl FnMut(&T) -> K,
+) -> Vec<T> {
+    let keyed = items.into_iter().enumerate().map(|(i, item)| Keyed {
+        key: key(&item),
+        rank: Reverse(i),
+        item,
+    });
+    largest(keyed, k)
+        .into_iter()
+        .map(|keyed| keyed.item)
+        .collect()
+}
+
+/// the `k` items with the smallest keys, smallest first, see [`top_k_by_key`].
+pub fn bottom_k_by_key<T, K: Ord>(
+    items: impl IntoIterator<Item = T>,
+    k: usize,
+    mut key: impl FnMut(&T) -> K,
+) -> Vec<T> {
+    top_k_by_key(items, k, |item| Reverse(key(item)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        let numbers = [5, 1, 9, 3, 9, 7, 2];
+        assert_eq!(top_k(numbers, 3), vec![9, 9, 7]);
+        assert_eq!(bottom_k(numbers, 3), vec![1, 2, 3]);
+        assert_eq!(top_k(numbers, 0), Vec::<i32>::new());
+        assert_eq!(top_k(numbers, 10).len(), 7);
+        assert_eq!(bottom_k(Vec::<i32>::new(), 2), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_by_key() {
+        let words = ["pear", "fig", "banana", "kiwi", "apple", "plum"];
+        assert_eq!(top_k_by_key(words, 2, |w| w.len()), vec!["banana", "apple"]);
+        // ties keep the earlier items, in order.
+        assert_eq!(
+            top_k_by_key(words, 3, |w| w.len()),
+            vec!["banana", "apple", "pear"]
+        );
+        assert_eq!(
+            bottom_k_by_key(words, 3, |w| w.len()),
+            vec!["fig", "pear", "kiwi"]
+        );
+        assert_eq!(
+            top_k_by_key(words, 1, |w| w.len()).first(),
+            words.iter().rev().max_by_key(|w| w.len())
+        );
+    }
+}
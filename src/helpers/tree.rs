@@ -0,0 +1,278 @@
+use std::ops::{Index, IndexMut};
+
+/// a handle to a node of a [`Tree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// the position of the node in the tree, nodes are numbered in insertion order.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// a tree whose nodes are stored in a `Vec` and refer to each other by [`NodeId`],
+/// so nodes can be navigated and changed without `Rc` or `RefCell`.
+#[derive(Debug, Clone)]
+pub struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(root: T) -> Self {
+        Tree {
+            nodes: vec![Node {
+                value: root,
+                parent: None,
+                children: Vec::new(),
+            }],
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /// the number of nodes, including the root.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// always `false`, a tree has at least its root.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add_child(&mut self, parent: NodeId, value: T) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            value,
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent.0].children.push(id);
+        id
+    }
+
+    /// `None` for the root.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    /// in insertion order.
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
+    }
+
+    /// the first child of `id` whose key, as returned by `key_of`, equals `key`.
+    pub fn child_by_key<K: PartialEq>(
+        &self,
+        id: NodeId,
+        key: K,
+        key_of: impl Fn(&T) -> K,
+    ) -> Option<NodeId> {
+        self.children(id)
+            .iter()
+            .copied()
+            .find(|&child| key_of(&self[child]) == key)
+    }
+
+    /// all node ids in insertion order, starting with the root.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /// the nodes of the subtree at `id`, every node before its children.
+    pub fn pre_order(&self, id: NodeId) -> PreOrder<'_, T> {
+        PreOrder {
+            tree: self,
+            stack: vec![id],
+        }
+    }
+
+    /// the nodes of the subtree at `id`, every node after its children.
+    pub fn post_order(&self, id: NodeId) -> PostOrder<'_, T> {
+        PostOrder {
+            tree: self,
+            stack: vec![(id, 0)],
+        }
+    }
+
+    /// folds the subtree at `id` bottom-up: `f` gets the value of a node and the results
+    /// of its children, e.g. to sum up sizes.
+    pub fn fold<A: Clone>(&self, id: NodeId, f: impl FnMut(&T, &[A]) -> A) -> A {
+        self.fold_nodes(self.post_order(id), f)[id.0]
+            .take()
+            .unwrap()
+    }
+
+    /// like [`Tree::fold`] for the whole tree, but returns the result of every node's subtree,
+    /// indexed by [`NodeId::index`].
+    pub fn fold_all<A: Clone>(&self, f: impl FnMut(&T, &[A]) -> A) -> Vec<A> {
+        self.fold_nodes(self.post_order(self.root()), f)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    fn fold_nodes<A: Clone>(
+        &self,
+        post_order: PostOrder<'_, T>,
+        mut f: impl FnMut(&T, &[A]) -> A,
+    ) -> Vec<Option<A>> {
+        let mut results: Vec<Option<A>> = vec![None; self.nodes.len()];
+        for id in post_order {
+            let children: Vec<A> = self
+                .children(id)
+                .iter()
+                .map(|c| results[c.0].clone().unwrap())
+                .collect();
+            results[id.0] = Some(f(&self[id], &children));
+        }
+        results
+    }
+
+    /// the nodes from the root down to `id`, both included.
+    pub fn path(&self, id: NodeId) -> Vec<NodeId> {
+        let mut path = vec![id];
+        let mut current = id;
+        while let Some(parent) = self.parent(current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl<T> Index<NodeId> for Tree<T> {
+    type Output = T;
+
+    fn index(&self, id: NodeId) -> &T {
+        &self.nodes[id.0].value
+    }
+}
+
+impl<T> IndexMut<NodeId> for Tree<T> {
+    fn index_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.nodes[id.0].value
+    }
+}
+
+/// iterator returned by [`Tree::pre_order`].
+pub struct PreOrder<'a, T> {
+    tree: &'a Tree<T>,
+    stack: Vec<NodeId>,
+}
+
+impl<T> Iterator for PreOrder<'_, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.stack.pop()?;
+        self.stack.extend(self.tree.children(id).iter().rev());
+        Some(id)
+    }
+}
+
+/// iterator returned by [`Tree::post_order`].
+pub struct PostOrder<'a, T> {
+    tree: &'a Tree<T>,
+    /// every node on the way down with the number of its children that were visited.
+    stack: Vec<(NodeId, usize)>,
+}
+
+impl<T> Iterator for PostOrder<'_, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        loop {
+            let (id, visited) = self.stack.last_mut()?;
+            let id = *id;
+            match self.tree.children(id).get(*visited) {
+                Some(&child) => {
+                    *visited += 1;
+                    self.stack.push((child, 0));
+                }
+                None => {
+                    self.stack.pop();
+                    return Some(id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //      a
+    //    / | \
+    //   b  c  d
+    //  / \     \
+    // e   f     g
+    fn example() -> Tree<(char, u32)> {
+        let mut tree = Tree::new(('a', 1));
+        let root = tree.root();
+        let b = tree.add_child(root, ('b', 2));
+        tree.add_child(root, ('c', 3));
+        let d = tree.add_child(root, ('d', 4));
+        tree.add_child(b, ('e', 5));
+        tree.add_child(b, ('f', 6));
+        tree.add_child(d, ('g', 7));
+        tree
+    }
+
+    fn names(tree: &Tree<(char, u32)>, ids: impl Iterator<Item = NodeId>) -> String {
+        ids.map(|id| tree[id].0).collect()
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut tree = example();
+        let root = tree.root();
+        assert_eq!(tree.len(), 7);
+        let d = tree.child_by_key(root, 'd', |n| n.0).unwrap();
+        assert_eq!(tree.child_by_key(root, 'e', |n| n.0), None);
+        let g = tree.children(d)[0];
+        assert_eq!(tree.parent(g), Some(d));
+        assert_eq!(tree.parent(root), None);
+        assert_eq!(names(&tree, tree.path(g).into_iter()), "adg");
+        tree[g].1 = 70;
+        assert_eq!(tree[g], ('g', 70));
+    }
+
+    #[test]
+    fn test_traversal() {
+        let tree = example();
+        let root = tree.root();
+        assert_eq!(names(&tree, tree.pre_order(root)), "abefcdg");
+        assert_eq!(names(&tree, tree.post_order(root)), "efbcgda");
+        let b = tree.children(root)[0];
+        assert_eq!(names(&tree, tree.post_order(b)), "efb");
+        assert_eq!(names(&tree, tree.ids()), "abcdefg");
+    }
+
+    #[test]
+    fn test_fold() {
+        let tree = example();
+        let sum = |node: &(char, u32), children: &[u32]| node.1 + children.iter().sum::<u32>();
+        assert_eq!(tree.fold(tree.root(), sum), 28);
+        let b = tree.children(tree.root())[0];
+        assert_eq!(tree.fold(b, sum), 13);
+        assert_eq!(tree.fold_all(sum), vec![28, 13, 3, 11, 5, 6, 7]);
+
+        let depth = tree.fold(tree.root(), |_, children: &[usize]| {
+            1 + children.iter().max().unwrap_or(&0)
+        });
+        assert_eq!(depth, 3);
+    }
+}
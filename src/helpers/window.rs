@@ -0,0 +1,137 @@
+/// the start of the first `k` consecutive bytes that are all different, in O(n).
+pub fn first_distinct_window(bytes: &[u8], k: usize) -> Option<usize> {
+    if k == 0 {
+        return Some(0);
+    }
+    let mut last_seen = [None; 256];
+    // the start of the longest run of distinct bytes that ends at the current one.
+    let mut start = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if let Some(previous) = last_seen[b as usize] {
+            start = start.max(previous + 1);
+        }
+        last_seen[b as usize] = Some(i);
+        if i + 1 - start >= k {
+            return Some(i + 1 - k);
+        }
+    }
+    None
+}
+
+/// iterator returned by [`windows_with_state`].
+pub struct WindowsWithState<'a, T, S, A, R> {
+    items: &'a [T],
+    k: usize,
+    next: usize,
+    state: S,
+    add: A,
+    remove: R,
+}
+
+impl<T, S, A, R> Iterator for WindowsWithState<'_, T, S, A, R>
+where
+    S: Clone,
+    A: FnMut(&mut S, &T),
+    R: FnMut(&mut S, &T),
+{
+    type Item = (usize, S);
+
+    fn next(&mut self) -> Option<(usize, S)> {
+        let start = self.next;
+        if start + self.k > self.items.len() {
+            return None;
+        }
+        if start == 0 {
+            for item in &self.items[..self.k] {
+                (self.add)(&mut self.state, item);
+            }
+        } else {
+            (self.remove)(&mut self.state, &self.items[start - 1]);
+            (self.add)(&mut self.state, &self.items[start + self.k - 1]);
+        }
+        self.next += 1;
+        Some((start, self.state.clone()))
+    }
+}
+
+/// slides a window of `k` items over `items` and keeps `state` up to date by calling `add` for
+/// every item that enters the window and `remove` for every item that leaves it.
+/// yields the start of each window with a copy of the state, e.g. counts of the items in it.
+pub fn windows_with_state<T, S, A, R>(
+    items: &[T],
+    k: usize,
+    state: S,
+    add: A,
+    remove: R,
+) -> WindowsWithState<'_, T, S, A, R>
+where
+    S: Clone,
+    A: FnMut(&mut S, &T),
+    R: FnMut(&mut S, &T),
+{
+    WindowsWithState {
+        items,
+        k,
+        next: 0,
+        state,
+        add,
+        remove,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_distinct_window() {
+        assert_eq!(
+            first_distinct_window(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4),
+            Some(3)
+        );
+        assert_eq!(
+            first_distinct_window(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14),
+            Some(5)
+        );
+        assert_eq!(first_distinct_window(b"abcd", 4), Some(0));
+        assert_eq!(first_distinct_window(b"abca", 4), None);
+        assert_eq!(first_distinct_window(b"aaaa", 1), Some(0));
+        assert_eq!(first_distinct_window(b"", 1), None);
+        assert_eq!(first_distinct_window(b"", 0), Some(0));
+    }
+
+    #[test]
+    fn test_windows_with_state() {
+        let sums: Vec<_> =
+            windows_with_state(&[1, 2, 3, 4, 5], 3, 0, |s, x| *s += x, |s, x| *s -= x).collect();
+        assert_eq!(sums, vec![(0, 6), (1, 9), (2, 12)]);
+
+        // the number of distinct letters in each window.
+        let letters = b"abacabb";
+        let distinct: Vec<_> = windows_with_state(
+            letters,
+            3,
+            ([0; 26], 0),
+            |(counts, distinct), b| {
+                counts[(b - b'a') as usize] += 1;
+                if counts[(b - b'a') as usize] == 1 {
+                    *distinct += 1;
+                }
+            },
+            |(counts, distinct), b| {
+                counts[(b - b'a') as usize] -= 1;
+                if counts[(b - b'a') as usize] == 0 {
+                    *distinct -= 1;
+                }
+            },
+        )
+        .map(|(_, (_, distinct))| distinct)
+        .collect();
+        assert_eq!(distinct, vec![2, 3, 2, 3, 2]);
+
+        assert_eq!(
+            windows_with_state(&[1, 2], 3, 0, |s, x| *s += x, |s, x| *s -= x).count(),
+            0
+        );
+    }
+}
@@ -7,6 +7,8 @@ use std::env;
 use std::fs;
 
 pub mod helpers;
+pub mod prelude;
+pub mod readme;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -15,6 +17,7 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
+        use advent_of_code::helpers::ocr;
         use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
         use std::fmt::Display;
         use std::time::Instant;
@@ -25,6 +28,16 @@ macro_rules! solve {
             let elapsed = timer.elapsed();
             match result {
                 Some(result) => {
+                    let mut result = result.to_string();
+                    // pictures of letters are printed as is, followed by the decoded text.
+                    if let Some(text) = result
+                        .contains('\n')
+                        .then(|| ocr::recognize(&result))
+                        .flatten()
+                    {
+                        println!("{}", result.trim_end());
+                        result = text;
+                    }
                     println!(
                         "{} {}(elapsed: {:.2?}){}",
                         result, ANSI_ITALIC, elapsed, ANSI_RESET
@@ -54,27 +67,86 @@ fn parse_time(val: &str, postfix: &str) -> f64 {
     val.split(postfix).next().unwrap().parse().unwrap()
 }
 
+/// parses the timing of a solver output line in milliseconds.
+fn parse_elapsed(line: &str) -> Option<f64> {
+    if !line.contains("elapsed:") {
+        return None;
+    }
+    let timing = line.split("(elapsed: ").last().unwrap();
+    // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    if timing.contains("ns)") {
+        Some(0_f64) // range below rounding precision.
+    } else if timing.contains("µs)") {
+        Some(parse_time(timing, "µs") / 1000_f64)
+    } else if timing.contains("ms)") {
+        Some(parse_time(timing, "ms"))
+    } else if timing.contains("s)") {
+        Some(parse_time(timing, "s") * 1000_f64)
+    } else {
+        None
+    }
+}
+
 pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
+    output
+        .lines()
+        .fold(0_f64, |acc, l| acc + parse_elapsed(l).unwrap_or(0_f64))
+}
+
+/// the result of one part as printed by `solve!`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    /// `None` if the part printed "not solved.".
+    pub answer: Option<String>,
+    /// in milliseconds.
+    pub elapsed: Option<f64>,
+}
+
+/// parses the output of a solution binary into one result per part.
+/// the answer is everything printed between the part header and the timing, so multi-line
+/// answers are kept whole. a picture of letters that `solve!` could decode yields the letters.
+pub fn parse_part_results(output: &str) -> Vec<PartResult> {
+    let mut results: Vec<PartResult> = Vec::new();
+    // the lines of the current part in front of the one with the timing.
+    let mut pending: Vec<&str> = Vec::new();
+    for l in output.lines() {
+        if let Some(header) = l.strip_prefix("🎄 ") {
+            let part = header
+                .trim_start_matches(ANSI_BOLD)
+                .strip_prefix("Part ")
+                .and_then(|p| p.split(ANSI_RESET).next())
+                .and_then(|p| p.split_whitespace().next())
+                .and_then(|p| p.parse().ok());
+            if let Some(part) = part {
+                results.push(PartResult {
+                    part,
+                    answer: None,
+                    elapsed: None,
+                });
+                pending.clear();
+            }
+        } else if let Some(result) = results.last_mut() {
+            if let Some(elapsed) = parse_elapsed(l) {
+                let last = l.split(ANSI_ITALIC).next().unwrap();
+                let last = last.split("(elapsed: ").next().unwrap().trim();
+                let picture = pending.join("\n");
+                let decoded = helpers::ocr::recognize(&picture);
+                let answer = if pending.is_empty() || decoded.as_deref() == Some(last) {
+                    last.to_string()
+                } else {
+                    format!("{}\n{}", picture, last).trim_end().to_string()
+                };
+                result.answer = Some(answer);
+                result.elapsed = Some(elapsed);
+                pending.clear();
             } else {
-                acc
+                pending.push(l);
             }
         }
-    })
+    }
+    results
 }
 
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -122,4 +194,52 @@ mod tests {
             10400.50_f64
         );
     }
+
+    #[test]
+    fn test_parse_part_results() {
+        let output = format!(
+            "🎄 {b}Part 1{r} 🎄\n[\n    420,\n]\n13140 {i}(elapsed: 1.50ms){r}\n🎄 {b}Part 2{r} 🎄\nnot solved.\n",
+            b = ANSI_BOLD,
+            r = ANSI_RESET,
+            i = ANSI_ITALIC
+        );
+        assert_eq!(
+            parse_part_results(&output),
+            vec![
+                PartResult {
+                    part: 1,
+                    answer: Some("[\n    420,\n]\n13140".to_string()),
+                    elapsed: Some(1.5_f64),
+                },
+                PartResult {
+                    part: 2,
+                    answer: None,
+                    elapsed: None,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_picture_results() {
+        let letters = [
+            "#..#.###.",
+            "#..#..#..",
+            "####..#..",
+            "#..#..#..",
+            "#..#..#..",
+            "#..#.###.",
+        ]
+        .join("\n");
+        let unknown = "##..##\n.#..#.";
+        let output = format!(
+            "🎄 Part 1 🎄\n{}\nHI (elapsed: 1.00ms)\n🎄 Part 2 🎄\n{} (elapsed: 2.00ms)\n",
+            letters, unknown
+        );
+        let answers: Vec<_> = parse_part_results(&output)
+            .into_iter()
+            .map(|r| r.answer.unwrap())
+            .collect();
+        assert_eq!(answers, vec!["HI", unknown]);
+    }
 }
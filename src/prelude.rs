@@ -0,0 +1,10 @@
+/*
+ * Common imports for solutions, use them with `use advent_of_code::prelude::*;`.
+ */
+pub use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
+
+pub use itertools::Itertools;
+pub use parse_display::{Display, FromStr};
+pub use rayon::prelude::*;
+
+pub use crate::helpers::*;
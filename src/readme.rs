@@ -0,0 +1,239 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// marks the start and end of the results table in `README.md`.
+pub const TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// returns the byte range between the two table markers.
+fn table_range(readme: &str) -> Option<(usize, usize)> {
+    let start = readme.find(TABLE_MARKER)? + TABLE_MARKER.len();
+    let end = start + readme[start..].find(TABLE_MARKER)?;
+    Some((start, end))
+}
+
+/// returns the text between the two table markers.
+pub fn table_section(readme: &str) -> Option<&str> {
+    table_range(readme).map(|(start, end)| &readme[start..end])
+}
+
+/// replaces the text between the two table markers, leaving the markers intact.
+pub fn replace_table_section(readme: &str, section: &str) -> Option<String> {
+    let (start, end) = table_range(readme)?;
+    Some(format!("{}{}{}", &readme[..start], section, &readme[end..]))
+}
+
+/// reads the year from the `## <year> Results` heading of the results table.
+pub fn table_year(readme: &str) -> Option<i16> {
+    table_section(readme)?.lines().find_map(|l| {
+        l.strip_prefix("## ")?
+            .strip_suffix(" Results")?
+            .trim()
+            .parse()
+            .ok()
+    })
+}
+
+pub fn day_url(year: i16, day: u8) -> String {
+    format!("https://adventofcode.com/{}/day/{}", year, day)
+}
+
+/// reads the day from a `| [Day <day>](...) | ... |` table row.
+fn row_day(row: &str) -> Option<u8> {
+    let rest = row.trim_start().strip_prefix("| [Day ")?;
+    rest[..rest.find(']')?].parse().ok()
+}
+
+pub fn table_header(year: i16) -> String {
+    format!(
+        "\n## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    )
+}
+
+/// adds an empty row for `day` to the results table, keeping rows ordered by day.
+/// returns `None` if the README has no table markers, tracks a different year or the row already exists.
+pub fn add_day_row(readme: &str, year: i16, day: u8) -> Option<String> {
+    let section = table_section(readme)?;
+    if table_year(readme).is_some_and(|y| y != year) {
+        return None;
+    }
+    let mut lines: Vec<String> = if section.contains("| Day |") {
+        section.lines().map(|l| l.to_string()).collect()
+    } else {
+        table_header(year).lines().map(|l| l.to_string()).collect()
+    };
+
+    if lines.iter().any(|l| row_day(l) == Some(day)) {
+        return None;
+    }
+
+    let header_end = lines.iter().position(|l| l.starts_with("| :---"))? + 1;
+    let position = lines
+        .iter()
+        .enumerate()
+        .skip(header_end)
+        .find(|(_, l)| row_day(l).is_none_or(|d| d > day))
+        .map(|(i, _)| i)
+        .unwrap_or(lines.len());
+    lines.insert(
+        position,
+        format!("| [Day {}]({}) |  |  |", day, day_url(year, day)),
+    );
+
+    replace_table_section(readme, &format!("{}\n", lines.join("\n")))
+}
+
+/// the progress of a single day, as shown in one row of the results table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayProgress {
+    pub day: u8,
+    pub stars: [bool; 2],
+    /// in milliseconds.
+    pub timings: [Option<f64>; 2],
+}
+
+/// reads the rows of the results table back into their progress.
+pub fn parse_table(readme: &str) -> Vec<DayProgress> {
+    let section = match table_section(readme) {
+        Some(section) => section,
+        None => return vec![],
+    };
+    section
+        .lines()
+        .filter_map(|l| {
+            let day = row_day(l)?;
+            let cells: Vec<&str> = l.split('|').map(|c| c.trim()).collect();
+            let timing =
+                |i: usize| -> Option<f64> { cells.get(i)?.strip_suffix("ms")?.parse().ok() };
+            Some(DayProgress {
+                day,
+                stars: [cells.get(2) == Some(&"⭐"), cells.get(3) == Some(&"⭐")],
+                timings: [timing(4), timing(5)],
+            })
+        })
+        .collect()
+}
+
+/// renders the text between the table markers for the given days.
+/// pass `with_timings` to add a timing column per part.
+pub fn render_table(year: i16, days: &[DayProgress], with_timings: bool) -> String {
+    let mut table = if with_timings {
+        format!(
+            "\n## {} Results\n\n| Day | Part 1 | Part 2 | Time 1 | Time 2 |\n| :---: | :---: | :---: | ---: | ---: |\n",
+            year
+        )
+    } else {
+        table_header(year)
+    };
+    for progress in days.iter() {
+        let star = |solved: bool| if solved { "⭐" } else { "" };
+        table.push_str(&format!(
+            "| [Day {}]({}) | {} | {} |",
+            progress.day,
+            day_url(year, progress.day),
+            star(progress.stars[0]),
+            star(progress.stars[1])
+        ));
+        if with_timings {
+            for timing in progress.timings.iter() {
+                match timing {
+                    Some(ms) => table.push_str(&format!(" {:.2}ms |", ms)),
+                    None => table.push_str("  |"),
+                }
+            }
+        }
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# AoC\n\n<!--- advent_readme_stars table --->\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n| [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |  |\n<!--- advent_readme_stars table --->\n\nrest\n";
+
+    #[test]
+    fn test_table_year() {
+        assert_eq!(table_year(README), Some(2022));
+        assert_eq!(table_year("no table"), None);
+    }
+
+    #[test]
+    fn test_add_day_row() {
+        let updated = add_day_row(README, 2022, 2).unwrap();
+        assert_eq!(
+            table_section(&updated).unwrap(),
+            "\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n| [Day 2](https://adventofcode.com/2022/day/2) |  |  |\n| [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |  |\n"
+        );
+        assert!(updated.starts_with("# AoC\n\n<!--- advent_readme_stars table --->\n"));
+        assert!(updated.ends_with("<!--- advent_readme_stars table --->\n\nrest\n"));
+
+        let appended = add_day_row(&updated, 2022, 10).unwrap();
+        assert!(table_section(&appended)
+            .unwrap()
+            .ends_with("| [Day 10](https://adventofcode.com/2022/day/10) |  |  |\n"));
+
+        assert_eq!(add_day_row(README, 2022, 1), None);
+        assert_eq!(add_day_row(README, 2021, 2), None);
+    }
+
+    #[test]
+    fn test_add_day_row_to_empty_table() {
+        let readme = "<!--- advent_readme_stars table ---><!--- advent_readme_stars table --->";
+        assert_eq!(
+            add_day_row(readme, 2021, 1).unwrap(),
+            "<!--- advent_readme_stars table --->\n## 2021 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2021/day/1) |  |  |\n<!--- advent_readme_stars table --->"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let days = [
+            DayProgress {
+                day: 1,
+                stars: [true, true],
+                timings: [Some(0.035), Some(1.5)],
+            },
+            DayProgress {
+                day: 2,
+                stars: [true, false],
+                timings: [Some(12.0), None],
+            },
+        ];
+        assert_eq!(
+            render_table(2022, &days, false),
+            "\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |  |\n"
+        );
+        assert!(render_table(2022, &days, true).ends_with(
+            "| :---: | :---: | :---: | ---: | ---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ | 0.04ms | 1.50ms |\n| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |  | 12.00ms |  |\n"
+        ));
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let days = [
+            DayProgress {
+                day: 1,
+                stars: [true, true],
+                timings: [Some(0.04), Some(1.5)],
+            },
+            DayProgress {
+                day: 2,
+                stars: [true, false],
+                timings: [Some(12.0), None],
+            },
+        ];
+        let readme = format!(
+            "{}{}{}",
+            TABLE_MARKER,
+            render_table(2022, &days, true),
+            TABLE_MARKER
+        );
+        assert_eq!(parse_table(&readme), days);
+        assert_eq!(parse_table(README).len(), 2);
+        assert_eq!(parse_table(README)[1].stars, [true, false]);
+    }
+}